@@ -0,0 +1,134 @@
+use common::parse::lines;
+use common::ParseError;
+use std::path::{Path, PathBuf};
+
+// the answers my puzzle inputs are known to give, one per line in answers.txt at the root
+// of the workspace:
+//
+//     # day  part  answer
+//       5    1     SHMSDGZVC
+//       10   2     ####..##..#...\n#....#..#.#...\n...
+//       17   1     ?
+//
+// multi-line answers are written with \n escapes, and ? marks a part that was solved
+// but whose answer never got written down. a part that isn't listed isn't solved yet
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Expected {
+    pub day   : u32,
+    pub part  : u32,
+    pub answer: Option<String>
+}
+
+pub fn path() -> PathBuf {
+    crate::workspace_root().join("answers.txt")
+}
+
+pub fn load() -> Result<Vec<Expected>, String> {
+    read(&path())
+}
+
+pub fn read(path: &Path) -> Result<Vec<Expected>, String> {
+    let contents = std::fs::read_to_string(path)
+                           .map_err(|error| format!("couldn't read {}: {}", path.display(), error))?;
+
+    parse(&contents).map_err(|error| format!("{}\n{}", path.display(), error))
+}
+
+pub fn parse(s: &str) -> Result<Vec<Expected>, ParseError> {
+    let mut expected = vec![];
+
+    for line in lines(s) {
+        let mut scanner = line.scanner();
+        scanner.skip_spaces();
+
+        if scanner.is_done() || scanner.peek() == Some('#') { continue }
+
+        let day = scanner.number()?;
+        scanner.tag(" ")?;
+        scanner.skip_spaces();
+
+        let part = match scanner.one_of("12")? { '1' => 1, _ => 2 };
+        scanner.tag(" ")?;
+        scanner.skip_spaces();
+
+        let answer = match scanner.rest().trim_end() {
+            ""     => return Err(scanner.error("an answer, or ? if it isn't known")),
+            "?"    => None,
+            answer => Some(unescape(answer))
+        };
+
+        expected.push(Expected { day, part, answer });
+    }
+
+    Ok(expected)
+}
+
+// the expected answer for a part, if it's been recorded
+pub fn find(expected: &[Expected], day: u32, part: u32) -> Option<&Expected> {
+    expected.iter().find(|e| e.day == day && e.part == part)
+}
+
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n'))  => { unescaped.push('\n'); chars.next(); },
+            ('\\', Some('\\')) => { unescaped.push('\\'); chars.next(); },
+            (c, _)             => unescaped.push(c)
+        }
+    }
+
+    unescaped
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let expected = parse("# day  part  answer\n\
+                              \n\
+                              1    1     69626\n\
+                              10   2     #..#\\n.##.\\n\n\
+                              17   1     ?").unwrap();
+
+        assert_eq!(expected, vec![
+            Expected { day:  1, part: 1, answer: Some("69626".to_string()) },
+            Expected { day: 10, part: 2, answer: Some("#..#\n.##.\n".to_string()) },
+            Expected { day: 17, part: 1, answer: None }
+        ]);
+
+        assert_eq!(find(&expected, 17, 1).map(|e| e.day), Some(17));
+        assert_eq!(find(&expected, 17, 2), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("1 3 100").err().unwrap().column, 3);
+        assert_eq!(parse("1 1").err().unwrap().column, 4);
+    }
+
+    #[test]
+    fn test_escape() {
+        let answer = "a\\b\nc";
+        assert_eq!(escape(answer), "a\\\\b\\nc");
+        assert_eq!(unescape(&escape(answer)), answer);
+    }
+
+    // the checked-in file has to stay readable
+    #[test]
+    fn test_load() {
+        let expected = load().unwrap();
+        assert_eq!(find(&expected, 12, 1).and_then(|e| e.answer.as_deref()), Some("534"));
+    }
+}
@@ -0,0 +1,286 @@
+use crate::days::Day;
+use common::ParseError;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// the three things timed for each day
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2
+}
+
+impl Stage {
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2"
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [Stage::Parse, Stage::Part1, Stage::Part2]
+            .into_iter()
+            .find(|stage| stage.name() == name)
+    }
+
+    fn of_part(part: u32) -> Self {
+        match part {
+            1 => Stage::Part1,
+            2 => Stage::Part2,
+            _ => panic!("there are only two parts to a day")
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min   : Duration,
+    pub max   : Duration
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "can't summarize zero samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 1 { sorted[middle] }
+                     else                     { (sorted[middle - 1] + sorted[middle]) / 2 };
+
+        Stats {
+            median,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1]
+        }
+    }
+}
+
+pub struct Measurement {
+    pub day  : u32,
+    pub stage: Stage,
+    pub stats: Stats
+}
+
+// solve a day over and over, throwing away the warmup runs, and summarize how long each
+// stage took. every run parses the input fresh so parse times are real
+pub fn bench(day     : &Day,
+             contents: &str,
+             parts   : &[u32],
+             warmup  : usize,
+             runs    : usize) -> Result<Vec<Measurement>, ParseError>
+{
+    let mut samples: BTreeMap<Stage, Vec<Duration>> = BTreeMap::new();
+
+    for i in 0..warmup + runs {
+        let run = day.solve(contents, parts)?;
+        if i < warmup { continue }
+
+        samples.entry(Stage::Parse).or_default().push(run.parse);
+
+        for part in run.parts {
+            samples.entry(Stage::of_part(part.part)).or_default().push(part.time);
+        }
+    }
+
+    Ok(samples.into_iter()
+              .map(|(stage, samples)| Measurement {
+                  day: day.number,
+                  stage,
+                  stats: Stats::from_samples(&samples)
+              })
+              .collect())
+}
+
+
+/* Baseline */
+
+// the median of each stage from an earlier run, one per line in .aoc/bench.txt:
+//
+//     day  stage  nanoseconds
+//     12   part1  84211345
+//
+// tab separated. timings only mean anything on the machine they came from, so the file
+// stays out of the repo with the rest of .aoc
+pub struct Baseline {
+    path   : PathBuf,
+    medians: BTreeMap<(u32, Stage), Duration>
+}
+
+impl Baseline {
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join("bench.txt");
+
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(format!("couldn't read {}: {}", path.display(), error))
+        };
+
+        let medians = contents.lines()
+                              .enumerate()
+                              .map(|(number, line)| {
+                                  from_line(line).ok_or_else(|| {
+                                      format!("{} line {}: expected day, stage and nanoseconds",
+                                              path.display(),
+                                              number + 1)
+                                  })
+                              })
+                              .collect::<Result<_, _>>()?;
+
+        Ok(Baseline { path, medians })
+    }
+
+    pub fn get(&self, day: u32, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+
+    pub fn set(&mut self, day: u32, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let contents: String = self.medians.iter()
+                                           .map(|(&(day, stage), median)| {
+                                               format!("{}\t{}\t{}\n", day, stage, median.as_nanos())
+                                           })
+                                           .collect();
+
+        let write = || -> std::io::Result<()> {
+            std::fs::create_dir_all(self.path.parent().unwrap())?;
+            std::fs::write(&self.path, contents)
+        };
+
+        write().map_err(|error| format!("couldn't write {}: {}", self.path.display(), error))
+    }
+}
+
+fn from_line(line: &str) -> Option<((u32, Stage), Duration)> {
+    let mut fields = line.split('\t');
+
+    let day   = fields.next()?.parse().ok()?;
+    let stage = Stage::from_name(fields.next()?)?;
+    let nanos = fields.next()?.parse().ok()?;
+
+    if fields.next().is_some() { return None }
+
+    Some(((day, stage), Duration::from_nanos(nanos)))
+}
+
+
+/* Comparing */
+
+// a stage this much slower than its baseline isn't worth flagging however large the
+// percentage, it's down to the timer and whatever else the machine was doing
+const NOISE: Duration = Duration::from_micros(20);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    Within(f64),
+    Regressed(f64)
+}
+
+// how a new median compares to the baseline, as a percentage. slower by more than the
+// threshold percent is a regression. a baseline of nothing at all has no percentages
+pub fn compare(baseline: Duration, median: Duration, threshold: f64) -> Option<Change> {
+    if baseline.is_zero() { return None }
+
+    let percent = (median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+
+    if percent > threshold && median.saturating_sub(baseline) > NOISE {
+        Some(Change::Regressed(percent))
+    } else {
+        Some(Change::Within(percent))
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn ms(n: u64) -> Duration { Duration::from_millis(n) }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::from_samples(&[ms(5), ms(1), ms(3)]),
+                   Stats { median: ms(3), min: ms(1), max: ms(5) });
+
+        assert_eq!(Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]),
+                   Stats { median: ms(3), min: ms(1), max: ms(9) });
+    }
+
+    #[test]
+    fn test_bench() {
+        let day = days::get(1).unwrap();
+        let measurements = bench(&day, "1000\n2000\n\n3000\n", &[1, 2], 1, 3).unwrap();
+
+        let stages: Vec<Stage> = measurements.iter().map(|m| m.stage).collect();
+        assert_eq!(stages, vec![Stage::Parse, Stage::Part1, Stage::Part2]);
+        assert!(measurements.iter().all(|m| m.day == 1 && m.stats.min <= m.stats.median));
+
+        let part2 = bench(&day, "1000\n2000\n\n3000\n", &[2], 0, 1).unwrap();
+        assert_eq!(part2.iter().map(|m| m.stage).collect::<Vec<_>>(), vec![Stage::Parse, Stage::Part2]);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let dir = temp_dir("baseline");
+
+        let mut baseline = Baseline::load(&dir).unwrap();
+        assert_eq!(baseline.get(12, Stage::Part1), None);
+
+        baseline.set(12, Stage::Part1, Duration::from_nanos(84_211_345));
+        baseline.set( 1, Stage::Parse, ms(2));
+        baseline.save().unwrap();
+
+        assert_eq!(std::fs::read_to_string(dir.join("bench.txt")).unwrap(),
+                   "1\tparse\t2000000\n\
+                    12\tpart1\t84211345\n");
+
+        let baseline = Baseline::load(&dir).unwrap();
+        assert_eq!(baseline.get(12, Stage::Part1), Some(Duration::from_nanos(84_211_345)));
+
+        std::fs::write(dir.join("bench.txt"), "12\tpart3\t100\n").unwrap();
+        assert!(Baseline::load(&dir).err().unwrap().ends_with("line 1: expected day, stage and nanoseconds"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        assert!(matches!(compare(ms(100), ms(105), 10.0), Some(Change::Within(p)) if (p - 5.0).abs() < 1e-9));
+        assert!(matches!(compare(ms(100), ms(150), 10.0), Some(Change::Regressed(p)) if (p - 50.0).abs() < 1e-9));
+        assert!(matches!(compare(ms(100), ms(50), 10.0), Some(Change::Within(p)) if p < 0.0));
+
+        // twice as slow, but only by a few microseconds
+        assert!(matches!(compare(Duration::from_micros(5), Duration::from_micros(10), 10.0), Some(Change::Within(_))));
+
+        // faster by more than a threshold below zero, which the command line doesn't allow
+        // but mustn't take one duration from a smaller one, and a baseline of nothing
+        assert!(matches!(compare(ms(100), ms(50), -60.0), Some(Change::Within(_))));
+        assert_eq!(compare(Duration::ZERO, ms(50), 10.0), None);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::config::Config;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+// the site asks that automated tools say who they are
+const USER_AGENT: &str = "aoc-2022 runner, by github.com/jasonincanada";
+
+// a logged-in connection to the Advent of Code site, or whatever base_url points at
+pub struct Client {
+    base_url: String,
+    session : String,
+    agent   : ureq::Agent
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession,
+    Status(u16, String),
+    Transport(String),
+
+    // the request went fine, but what came back couldn't be saved here
+    Io(PathBuf, io::Error)
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, ClientError> {
+        let session = config.session.clone().ok_or(ClientError::NoSession)?;
+
+        Ok(Client {
+            base_url: config.base_url.clone(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build()
+        })
+    }
+
+    // eg get("/2022/day/16/input")
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let request = self.agent.get(&format!("{}{}", self.base_url, path))
+                                .set("Cookie", &format!("session={}", self.session));
+
+        read_response(request.call())
+    }
+
+    // eg post("/2022/day/16/answer", &[("level", "1"), ("answer", "2119")])
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let request = self.agent.post(&format!("{}{}", self.base_url, path))
+                                .set("Cookie", &format!("session={}", self.session));
+
+        read_response(request.send_form(form))
+    }
+}
+
+fn read_response(result: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match result {
+        Ok(response) => response.into_string()
+                                .map_err(|error| ClientError::Transport(error.to_string())),
+
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(ClientError::Status(code, body.trim().to_string()))
+        },
+
+        Err(error) => Err(ClientError::Transport(error.to_string()))
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(f, "no session cookie, set session in .aoc/config or AOC_SESSION"),
+
+            // the site answers a bad or expired cookie with a 400 or 500
+            ClientError::Status(400 | 500, _) => write!(f, "the server rejected the session cookie, it may have expired"),
+            ClientError::Status(404, _)       => write!(f, "not found, the puzzle may not be unlocked yet"),
+            ClientError::Status(code, body)   => write!(f, "the server answered {}: {}", code, body),
+
+            ClientError::Transport(error) => write!(f, "{}", error),
+            ClientError::Io(path, error)  => write!(f, "couldn't save {}: {}", path.display(), error)
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
@@ -0,0 +1,98 @@
+use std::path::Path;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// settings for talking to the Advent of Code site. they're read from .aoc/config in the
+// workspace, which is never committed, and can be overridden by environment variables:
+//
+//     # the value of the "session" cookie, from the browser's devtools
+//     session  = 53616c7465645f5f...
+//
+//     # point at a local server instead, eg for testing
+//     base_url = http://127.0.0.1:8080
+//
+pub struct Config {
+    pub session : Option<String>,
+    pub base_url: String
+}
+
+impl Config {
+    // the config file in a state directory, then AOC_SESSION and AOC_BASE_URL on top
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join("config");
+
+        let file = match std::fs::read_to_string(&path) {
+            Ok(contents) => Some(contents),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+            Err(error) => return Err(format!("couldn't read {}: {}", path.display(), error))
+        };
+
+        Config::from_sources(file.as_deref(), |key| std::env::var(key).ok())
+    }
+
+    // kept apart from load() so tests don't have to touch the real environment
+    pub fn from_sources(file: Option<&str>,
+                        env : impl Fn(&str) -> Option<String>) -> Result<Self, String>
+    {
+        let mut config = Config {
+            session : None,
+            base_url: DEFAULT_BASE_URL.to_string()
+        };
+
+        for (number, line) in file.unwrap_or("").lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("config line {}: expected key = value", number + 1))
+            };
+
+            match key.trim() {
+                "session"  => config.session  = Some(value.trim().to_string()),
+                "base_url" => config.base_url = value.trim().to_string(),
+                key        => return Err(format!("config line {}: unknown setting \"{}\"", number + 1, key))
+            }
+        }
+
+        if let Some(session)  = env("AOC_SESSION")  { config.session  = Some(session) }
+        if let Some(base_url) = env("AOC_BASE_URL") { config.base_url = base_url }
+
+        config.base_url = config.base_url.trim_end_matches('/').to_string();
+
+        Ok(config)
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_sources() {
+        let file = "# comment\n\
+                    session = abc123\n\
+                    \n\
+                    base_url = http://localhost:1234/\n";
+
+        let config = Config::from_sources(Some(file), |_| None).unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:1234");
+
+        // the environment wins over the file
+        let config = Config::from_sources(Some(file), |key| (key == "AOC_SESSION").then(|| "xyz".to_string())).unwrap();
+        assert_eq!(config.session.as_deref(), Some("xyz"));
+
+        let config = Config::from_sources(None, |_| None).unwrap();
+        assert_eq!(config.session, None);
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+
+    #[test]
+    fn test_from_sources_errors() {
+        assert!(Config::from_sources(Some("session abc"), |_| None).is_err());
+        assert!(Config::from_sources(Some("cookie = abc"), |_| None).is_err());
+    }
+}
@@ -0,0 +1,49 @@
+use crate::run::{solve, Run};
+use common::ParseError;
+
+// a day's solution, linked in from its crate as a library
+pub struct Day {
+    pub number: u32,
+    solve: fn(&str, &[u32]) -> Result<Run, ParseError>
+}
+
+impl Day {
+    // parse the contents of an input file then run the requested parts against it
+    pub fn solve(&self, contents: &str, parts: &[u32]) -> Result<Run, ParseError> {
+        (self.solve)(contents, parts)
+    }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day { number:  1, solve: solve::<day_01::Day01> },
+        Day { number:  2, solve: solve::<day_02::Day02> },
+        Day { number:  3, solve: solve::<day_03::Day03> },
+        Day { number:  4, solve: solve::<day_04::Day04> },
+        Day { number:  5, solve: solve::<day_05::Day05> },
+        Day { number:  6, solve: solve::<day_06::Day06> },
+        Day { number:  7, solve: solve::<day_07::Day07> },
+        Day { number:  8, solve: solve::<day_08::Day08> },
+        Day { number:  9, solve: solve::<day_09::Day09> },
+        Day { number: 10, solve: solve::<day_10::Day10> },
+        Day { number: 11, solve: solve::<day_11::Day11> },
+        Day { number: 12, solve: solve::<day_12::Day12> },
+        Day { number: 13, solve: solve::<day_13::Day13> },
+        Day { number: 14, solve: solve::<day_14::Day14> },
+        Day { number: 15, solve: solve::<day_15::Day15> },
+        Day { number: 16, solve: solve::<day_16::Day16> },
+        Day { number: 17, solve: solve::<day_17::Day17> },
+        Day { number: 18, solve: solve::<day_18::Day18> },
+        Day { number: 19, solve: solve::<day_19::Day19> },
+        Day { number: 20, solve: solve::<day_20::Day20> },
+        Day { number: 21, solve: solve::<day_21::Day21> },
+        Day { number: 22, solve: solve::<day_22::Day22> },
+        Day { number: 23, solve: solve::<day_23::Day23> },
+        Day { number: 24, solve: solve::<day_24::Day24> },
+    ]
+}
+
+pub fn get(number: u32) -> Option<Day> {
+    all().into_iter()
+         .find(|day| day.number == number)
+}
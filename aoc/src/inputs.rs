@@ -0,0 +1,112 @@
+use crate::client::{Client, ClientError};
+use crate::config::Config;
+use std::path::{Path, PathBuf};
+
+// downloaded inputs are kept per year under the state directory, eg .aoc/inputs/2022/day_16.txt
+pub fn cache_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("inputs/{}/day_{:02}.txt", year, day))
+}
+
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf)
+}
+
+// download a day's input unless we already have it. an input never changes once it's
+// been handed out, so there's no reason to ask for it twice
+pub fn fetch(config: &Config,
+             dir   : &Path,
+             year  : u32,
+             day   : u32) -> Result<Fetched, ClientError>
+{
+    let path = cache_path(dir, year, day);
+    if path.exists() { return Ok(Fetched::Cached(path)) }
+
+    let client = Client::new(config)?;
+    let input  = client.get(&format!("/{}/day/{}/input", year, day))?;
+
+    let write = || -> std::io::Result<()> {
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(&path, input)
+    };
+
+    write().map_err(|error| ClientError::Io(path.clone(), error))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    fn config(stub: &Stub, session: Option<&str>) -> Config {
+        Config {
+            session : session.map(str::to_string),
+            base_url: stub.url.clone()
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_then_cached() {
+        let stub = Stub::serving(200, "1\n2\n3\n");
+        let dir  = temp_dir("fetch");
+        let config = config(&stub, Some("abc123"));
+
+        let Ok(Fetched::Downloaded(path)) = fetch(&config, &dir, 2022, 16) else { panic!("expected a download") };
+        assert_eq!(path, dir.join("inputs/2022/day_16.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/16/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+
+        // the second time is served from the cache without asking the server
+        assert!(matches!(fetch(&config, &dir, 2022, 16), Ok(Fetched::Cached(_))));
+        assert_eq!(stub.requests().len(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let stub = Stub::serving(404, "Please don't repeatedly request this endpoint before it unlocks!");
+        let dir  = temp_dir("fetch-errors");
+
+        assert!(matches!(fetch(&config(&stub, None), &dir, 2022, 25),
+                         Err(ClientError::NoSession)));
+
+        assert!(matches!(fetch(&config(&stub, Some("abc123")), &dir, 2022, 25),
+                         Err(ClientError::Status(404, _))));
+
+        // nothing was saved for the failed download, so there's no directory to clean up
+        assert!(!cache_path(&dir, 2022, 25).exists());
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_fetch_unsaved() {
+        let stub = Stub::serving(200, "1\n2\n3\n");
+        let dir  = temp_dir("fetch-unsaved");
+
+        // a file where the inputs directory should go
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("inputs"), "").unwrap();
+
+        let Err(error) = fetch(&config(&stub, Some("abc123")), &dir, 2022, 16) else { panic!("expected an error") };
+        assert!(matches!(&error, ClientError::Io(path, _) if *path == cache_path(&dir, 2022, 16)));
+        assert!(error.to_string().starts_with(&format!("couldn't save {}: ", cache_path(&dir, 2022, 16).display())));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
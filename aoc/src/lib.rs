@@ -0,0 +1,46 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod inputs;
+pub mod readme;
+pub mod report;
+pub mod run;
+pub mod scaffold;
+pub mod submit;
+
+#[cfg(test)]
+mod stub;
+
+use std::path::PathBuf;
+
+// the year these solutions are for
+pub const YEAR: u32 = 2022;
+
+// the root of the cargo workspace, one level up from this crate
+pub fn workspace_root() -> PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives inside the workspace")
+        .to_path_buf()
+}
+
+// settings, downloaded inputs and the like. it's in .gitignore
+pub fn state_dir() -> PathBuf {
+    workspace_root().join(".aoc")
+}
+
+// where a day's puzzle input is kept: days/day_16/input.txt if it's been put there by
+// hand, otherwise wherever `aoc fetch` downloaded it to
+pub fn input_path(day: u32) -> PathBuf {
+    let local = workspace_root().join(format!("days/day_{:02}/input.txt", day));
+    let cache = inputs::cache_path(&state_dir(), YEAR, day);
+
+    if !local.exists() && cache.exists() { cache } else { local }
+}
+
+// the example from the puzzle text, which every day keeps next to its Cargo.toml
+pub fn example_path(day: u32) -> PathBuf {
+    workspace_root().join(format!("days/day_{:02}/example.txt", day))
+}
@@ -0,0 +1,551 @@
+/*  Advent of Code 2022 runner
+
+    $ aoc run 16
+    $ aoc run all
+    $ aoc run 12 --part 2
+    $ aoc run 12 --example
+    $ aoc run 12 --input - < big.txt
+    $ aoc run all --format json
+    $ aoc run all --threads 1
+    $ aoc run 19 --example --log day19=debug
+    $ aoc bench 12 --runs 20
+    $ aoc bench all --threads 4
+    $ aoc fetch --day 16
+    $ aoc submit 16 1
+    $ aoc new 25 "Full of Hot Air"
+    $ aoc readme --check
+*/
+
+use aoc::bench::{self, Baseline, Change, Measurement};
+use aoc::config::Config;
+use aoc::days::{self, Day};
+use aoc::inputs::{self, Fetched};
+use aoc::{readme, scaffold};
+use aoc::report::{self, Format, Record};
+use aoc::submit;
+use aoc::run::{format_duration, Run};
+use clap::{Parser, Subcommand};
+use common::log::{self, Filter, Level};
+use common::{Answer, ParseError, Source};
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Show the solvers' own messages on stderr: -v for info, -vv for debug, -vvv for trace
+    #[arg(short, long = "verbose", global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Set log levels by day as well, eg "day19=debug" or "info,day17=trace"
+    #[arg(long, global = true)]
+    log: Option<String>
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle (or all of them) against its input.txt
+    Run {
+        /// Day number, or "all"
+        day: Selection,
+
+        /// Only run this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+
+        /// Read the input from this file instead, or - for stdin
+        #[arg(long, conflicts_with = "example")]
+        input: Option<String>,
+
+        /// Use the day's example.txt instead of its input
+        #[arg(long)]
+        example: bool,
+
+        /// Print a table (text), or a record per part with timings in nanoseconds (json, csv)
+        #[arg(long, default_value = "text")]
+        format: Format,
+
+        /// How many threads a day can split its slowest loops across, instead of one per core
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        threads: Option<usize>
+    },
+
+    /// Time the parse and each part of a day (or all of them) over repeated runs
+    Bench {
+        /// Day number, or "all"
+        day: Selection,
+
+        /// Only run this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+
+        /// Untimed runs to do first
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed runs to take the median, min and max of
+        #[arg(long, default_value_t = 10, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        runs: usize,
+
+        /// Percent slower than the baseline median that counts as a regression
+        #[arg(long, default_value_t = 10.0, value_parser = parse_threshold)]
+        threshold: f64,
+
+        /// Replace the stored baseline with these timings
+        #[arg(long)]
+        save: bool,
+
+        /// How many threads a day can split its slowest loops across, 1 so the medians
+        /// don't depend on the machine or AOC_THREADS
+        #[arg(long, default_value_t = 1, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        threads: usize
+    },
+
+    /// Download a day's puzzle input, unless it's already been downloaded
+    Fetch {
+        /// Puzzle year
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u32,
+
+        /// Day number from 1 to 25
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32
+    },
+
+    /// Solve one part of a day and send the answer to be checked
+    Submit {
+        /// Day number
+        #[arg(value_parser = parse_day)]
+        day: u32,
+
+        /// Part 1 or 2
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32
+    },
+
+    /// Start a new day from template.rs and add it to the workspace, the runner and the README
+    New {
+        /// Day number from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// The puzzle's title, eg "Full of Hot Air"
+        title: String
+    },
+
+    /// Regenerate the README's table of days and star count
+    Readme {
+        /// Don't write anything, just fail if the README is out of date
+        #[arg(long)]
+        check: bool
+    }
+}
+
+#[derive(Clone)]
+enum Selection {
+    All,
+    Day(u32)
+}
+
+impl std::str::FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" { return Ok(Selection::All) }
+
+        match s.parse() {
+            Ok(day) if days::get(day).is_some() => Ok(Selection::Day(day)),
+            _ => Err(format!("expected a day from 1 to {} or \"all\", got \"{}\"", days::all().len(), s))
+        }
+    }
+}
+
+// a day that's been solved, which is every one the runner links in
+fn parse_day(s: &str) -> Result<u32, String> {
+    match s.parse() {
+        Ok(day) if days::get(day).is_some() => Ok(day),
+        _ => Err(format!("expected a day from 1 to {}, got \"{}\"", days::all().len(), s))
+    }
+}
+
+// a percentage of zero or more, as anything faster than the baseline isn't a regression
+fn parse_threshold(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(percent) if percent >= 0.0 => Ok(percent),
+        _ => Err(format!("expected a percentage of 0 or more, got \"{}\"", s))
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    // without either of them, AOC_LOG decides the same as it does for a day run on its own
+    if cli.verbose > 0 || cli.log.is_some() {
+        let filter = Filter::new(Level::from_verbosity(cli.verbose)).parse(cli.log.as_deref().unwrap_or(""));
+
+        match filter {
+            Ok(filter) => log::set_filter(filter),
+            Err(error) => {
+                eprintln!("error: --log: {}", error);
+                std::process::exit(2)
+            }
+        }
+    }
+
+    match cli.command {
+        Command::Run    { day, part, input, example, format, threads } => run(day, part, input, example, format, threads),
+        Command::Fetch  { year, day }  => fetch(year, day),
+        Command::Submit { day, part }  => submit(day, part),
+        Command::New    { day, title } => new(day, &title),
+        Command::Readme { check }      => update_readme(check),
+
+        Command::Bench { day, part, warmup, runs, threshold, save, threads } => {
+            bench(day, part, warmup, runs, threshold, save, threads)
+        }
+    }
+}
+
+impl Selection {
+    fn days(&self) -> Vec<Day> {
+        match self {
+            Selection::All      => days::all(),
+            Selection::Day(day) => days::get(*day).into_iter().collect()
+        }
+    }
+}
+
+fn parts(part: Option<u32>) -> Vec<u32> {
+    match part {
+        Some(part) => vec![part],
+        None       => vec![1, 2]
+    }
+}
+
+// what happened when a day was run, printed as soon as it's done
+enum Outcome {
+    Skipped(String),
+    Unparsed(String, ParseError),
+    Solved(Run)
+}
+
+fn run(selection: Selection,
+       part     : Option<u32>,
+       input    : Option<String>,
+       example  : bool,
+       format   : Format,
+       threads  : Option<usize>)
+{
+    if input.is_some() && matches!(selection, Selection::All) {
+        eprintln!("error: --input only makes sense for a single day");
+        std::process::exit(2)
+    }
+
+    if let Some(threads) = threads { parallel::set_threads(threads) }
+
+    let parts = parts(part);
+    let days  = selection.days();
+
+    // the days are solved one at a time, as they're printed, so their timings don't
+    // overlap. the slowest of them split their own work across the threads instead
+    let outcomes = days.iter().map(|day| {
+        let source = match (&input, example) {
+            (Some(input), _) if input == "-" => Source::Stdin,
+            (Some(input), _)                 => Source::File(input.into()),
+            (None, true)                     => Source::Example(aoc::example_path(day.number)),
+            (None, false)                    => Source::File(aoc::input_path(day.number))
+        };
+
+        if let (Source::File(path), None) = (&source, &input) {
+            if !path.exists() {
+                return Outcome::Skipped(format!("no input at {}, try aoc fetch --day {}", path.display(), day.number))
+            }
+        }
+
+        let contents = match source.read() {
+            Ok(contents) => contents,
+            Err(error)   => return Outcome::Skipped(error)
+        };
+
+        match day.solve(&contents, &parts) {
+            Ok(run)    => Outcome::Solved(run),
+            Err(error) => Outcome::Unparsed(format!("couldn't parse {}", source), error)
+        }
+    });
+
+    if format == Format::Text { print_header() }
+
+    let mut total   = Duration::ZERO;
+    let mut records = vec![];
+
+    // a day that can't be run gets a row of its own in the table. the other formats only
+    // have room for answers, so it's reported on stderr instead
+    let skip = |day: u32, reason: String| {
+        if format == Format::Text { println!("{:>3}  {:>4}  ({})", day, "-", reason) }
+                             else { eprintln!("day {}: {}", day, reason) }
+    };
+
+    for (day, outcome) in days.iter().zip(outcomes) {
+        let run = match outcome {
+            Outcome::Solved(run)     => run,
+            Outcome::Skipped(reason) => { skip(day.number, reason); continue },
+            Outcome::Unparsed(reason, error) => {
+                skip(day.number, reason);
+                eprintln!("{}", error);
+                continue
+            }
+        };
+
+        total += run.parse + run.parts.iter().map(|part| part.time).sum::<Duration>();
+
+        match format {
+            Format::Text => print_run(day.number, &run),
+            _            => records.extend(Record::from_run(day.number, &run))
+        }
+    }
+
+    match format {
+        Format::Text => println!("{:>42}  {:>9}", "total", format_duration(total)),
+        Format::Json => print!("{}", report::json(&records)),
+        Format::Csv  => print!("{}", report::csv(&records))
+    }
+}
+
+// time each day, compare the medians with the stored baseline, and record any that
+// weren't in it yet (or all of them with --save)
+fn bench(selection: Selection,
+         part     : Option<u32>,
+         warmup   : usize,
+         runs     : usize,
+         threshold: f64,
+         save     : bool,
+         threads  : usize)
+{
+    parallel::set_threads(threads);
+
+    let parts = parts(part);
+
+    let mut baseline = Baseline::load(&aoc::state_dir()).unwrap_or_else(|error| {
+        eprintln!("error: {}", error);
+        std::process::exit(1)
+    });
+
+    let mut regressions = 0;
+
+    print_bench_header();
+
+    for day in selection.days() {
+        let path = aoc::input_path(day.number);
+
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            println!("{:>3}  {:<5}  (no input at {}, try aoc fetch --day {})", day.number, "-", path.display(), day.number);
+            continue
+        };
+
+        let measurements = match bench::bench(&day, &contents, &parts, warmup, runs) {
+            Ok(measurements) => measurements,
+            Err(error) => {
+                println!("{:>3}  {:<5}  (couldn't parse {})", day.number, "-", path.display());
+                eprintln!("{}", error);
+                continue
+            }
+        };
+
+        for measurement in measurements {
+            let stored = baseline.get(measurement.day, measurement.stage);
+            let change = stored.and_then(|stored| bench::compare(stored, measurement.stats.median, threshold));
+
+            if let Some(Change::Regressed(_)) = change { regressions += 1 }
+
+            print_measurement(&measurement, stored, change);
+
+            if save || stored.is_none() {
+                baseline.set(measurement.day, measurement.stage, measurement.stats.median);
+            }
+        }
+    }
+
+    if let Err(error) = baseline.save() {
+        eprintln!("error: {}", error);
+        std::process::exit(1)
+    }
+
+    // with --save the new timings are the baseline now, so they're reported but not failed
+    if regressions > 0 && !save {
+        eprintln!("{} timings regressed by more than {}% against the baseline", regressions, threshold);
+        std::process::exit(1)
+    }
+}
+
+fn fetch(year: u32, day: u32) {
+    let dir = aoc::state_dir();
+
+    let fetched = Config::load(&dir).and_then(|config| inputs::fetch(&config, &dir, year, day)
+                                                           .map_err(|error| error.to_string()));
+
+    match fetched {
+        Ok(Fetched::Cached(path))     => println!("already have {}", path.display()),
+        Ok(Fetched::Downloaded(path)) => println!("saved {}", path.display()),
+        Err(error) => {
+            eprintln!("error: couldn't fetch {} day {}: {}", year, day, error);
+            std::process::exit(1)
+        }
+    }
+}
+
+fn submit(day: u32, part: u32) {
+    let answer = match solve_one(day, part) {
+        Ok(answer) => answer,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1)
+        }
+    };
+
+    println!("day {} part {}: submitting {}", day, part, answer);
+
+    let dir = aoc::state_dir();
+
+    let submitted = Config::load(&dir).and_then(|config| submit::submit(&config, &dir, aoc::YEAR, day, part, &answer)
+                                                             .map_err(|error| error.to_string()));
+
+    match submitted {
+        Ok(submitted) => println!("{}: {}", submitted.verdict, submitted.message),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1)
+        }
+    }
+}
+
+fn new(day: u32, title: &str) {
+    match scaffold::create(&aoc::workspace_root(), day, title) {
+        Ok(created) => {
+            for file in created.written { println!("created {}", file.display()) }
+            for file in created.edited  { println!("updated {}", file.display()) }
+        },
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1)
+        }
+    }
+}
+
+fn update_readme(check: bool) {
+    let path = aoc::workspace_root().join("README.md");
+
+    let updated = readme::generate(&aoc::workspace_root()).and_then(|generated| {
+        let current = std::fs::read_to_string(&path).map_err(|error| error.to_string())?;
+        Ok((current, generated))
+    });
+
+    match updated {
+        Ok((current, generated)) if current == generated => println!("{} is up to date", path.display()),
+
+        Ok(_) if check => {
+            eprintln!("error: {} is out of date, run aoc readme", path.display());
+            std::process::exit(1)
+        },
+
+        Ok((_, generated)) => match std::fs::write(&path, generated) {
+            Ok(())     => println!("updated {}", path.display()),
+            Err(error) => {
+                eprintln!("error: couldn't write {}: {}", path.display(), error);
+                std::process::exit(1)
+            }
+        },
+
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1)
+        }
+    }
+}
+
+// the answer to one part of a day, as it would be typed into the site
+fn solve_one(day: u32, part: u32) -> Result<String, String> {
+    let path = aoc::input_path(day);
+    let contents = std::fs::read_to_string(&path)
+                           .map_err(|error| format!("couldn't read {}: {}", path.display(), error))?;
+
+    let run = days::get(day).expect("day was checked by clap")
+                            .solve(&contents, &[part])
+                            .map_err(|error| error.to_string())?;
+
+    if let Answer::Unsolved(reason) = &run.parts[0].answer {
+        return Err(format!("there's no answer to send: {}", reason))
+    }
+
+    let answer = run.parts[0].answer.to_string();
+
+    // day 10's CRT letters have to be read off the screen by a person
+    if answer.contains('\n') {
+        return Err(format!("the answer needs reading by hand:\n{}", answer))
+    }
+
+    Ok(answer)
+}
+
+
+/* Table */
+
+const ANSWER_WIDTH: usize = 20;
+
+fn print_header() {
+    println!("Day  Part  {:<ANSWER_WIDTH$}  {:>9}  {:>9}", "Answer", "Parse", "Solve");
+    println!("---  ----  {:-<ANSWER_WIDTH$}  {:->9}  {:->9}", "", "", "");
+}
+
+// one row per part. the parse time only shows on the first row for the day, and
+// multi-line answers (day 10's CRT) continue underneath in the answer column
+fn print_run(day: u32, run: &Run) {
+    for (i, part) in run.parts.iter().enumerate() {
+        let parse = if i == 0 { format_duration(run.parse) } else { String::new() };
+        let answer = part.answer.to_string();
+        let mut lines = answer.lines();
+
+        println!("{:>3}  {:>4}  {:<ANSWER_WIDTH$}  {:>9}  {:>9}",
+                 day,
+                 part.part,
+                 lines.next().unwrap_or(""),
+                 parse,
+                 format_duration(part.time));
+
+        for line in lines {
+            println!("{:>9}  {}", "", line);
+        }
+    }
+}
+
+
+/* Bench table */
+
+fn print_bench_header() {
+    println!("Day  Stage  {:>9}  {:>9}  {:>9}  {:>9}  {:>8}", "Median", "Min", "Max", "Baseline", "Change");
+    println!("---  -----  {:->9}  {:->9}  {:->9}  {:->9}  {:->8}", "", "", "", "", "");
+}
+
+fn print_measurement(measurement: &Measurement, stored: Option<Duration>, change: Option<Change>) {
+    let stats = &measurement.stats;
+
+    // a baseline of zero can't be compared with
+    let (change, flag) = match (change, stored) {
+        (Some(Change::Within(percent)), _)    => (format!("{:+.1}%", percent), ""),
+        (Some(Change::Regressed(percent)), _) => (format!("{:+.1}%", percent), "  regressed"),
+        (None, Some(_))                       => ("-".to_string(), ""),
+        (None, None)                          => ("new".to_string(), "")
+    };
+
+    let stored = stored.map(format_duration).unwrap_or_else(|| "-".to_string());
+
+    println!("{:>3}  {:<5}  {:>9}  {:>9}  {:>9}  {:>9}  {:>8}{}",
+             measurement.day,
+             measurement.stage,
+             format_duration(stats.median),
+             format_duration(stats.min),
+             format_duration(stats.max),
+             stored,
+             change,
+             flag);
+}
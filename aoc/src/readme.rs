@@ -0,0 +1,232 @@
+// `aoc readme`: the README's table of days and its star count, generated from the title
+// and description in each day's Cargo.toml under [package.metadata.aoc], and from which
+// parts answers.txt says are solved
+
+use crate::answers::{self, Expected};
+use std::path::Path;
+
+// the table sits between these two lines in the README
+const BEGIN: &str = "<!-- days: generated by `aoc readme` from each day's Cargo.toml and answers.txt -->";
+const END  : &str = "<!-- /days -->";
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub day        : u32,
+    pub title      : String,
+    pub description: String
+}
+
+// the README as it should be, given everything under root
+pub fn generate(root: &Path) -> Result<String, String> {
+    generate_with(root, None)
+}
+
+// the same, with a day that isn't under root yet, for a README that's ready to write
+// alongside the day's own files
+pub fn generate_with(root: &Path, new: Option<Entry>) -> Result<String, String> {
+    let path = root.join("README.md");
+    let readme = std::fs::read_to_string(&path)
+                         .map_err(|error| format!("couldn't read {}: {}", path.display(), error))?;
+
+    let mut entries = entries(root)?;
+    entries.extend(new);
+    entries.sort_by_key(|entry| entry.day);
+
+    let expected = answers::read(&root.join("answers.txt"))?;
+
+    render(&readme, &table(&entries, &expected), stars(&expected))
+}
+
+// every day under days/, in order
+pub fn entries(root: &Path) -> Result<Vec<Entry>, String> {
+    let dir = root.join("days");
+    let listing = std::fs::read_dir(&dir).map_err(|error| format!("couldn't read {}: {}", dir.display(), error))?;
+
+    let mut entries = vec![];
+
+    for dir in listing.flatten() {
+        let name = dir.file_name().to_string_lossy().to_string();
+        let Some(day) = name.strip_prefix("day_").and_then(|n| n.parse().ok()) else { continue };
+
+        let path = dir.path().join("Cargo.toml");
+        let manifest = std::fs::read_to_string(&path)
+                               .map_err(|error| format!("couldn't read {}: {}", path.display(), error))?;
+
+        let field = |key| metadata(&manifest, key).ok_or_else(|| {
+            format!("{} needs a {} under [package.metadata.aoc]", path.display(), key)
+        });
+
+        entries.push(Entry {
+            day,
+            title      : field("title")?,
+            description: field("description")?
+        });
+    }
+
+    entries.sort_by_key(|entry| entry.day);
+
+    Ok(entries)
+}
+
+// a quoted string under [package.metadata.aoc], like title = "Full of Hot Air"
+fn metadata(manifest: &str, key: &str) -> Option<String> {
+    manifest.lines()
+            .map(str::trim)
+            .skip_while(|line| *line != "[package.metadata.aoc]")
+            .skip(1)
+            .take_while(|line| !line.starts_with('['))
+            .filter_map(|line| line.split_once('='))
+            .find(|(name, _)| name.trim() == key)
+            .and_then(|(_, value)| {
+                let value = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+                Some(value.replace("\\\"", "\"").replace("\\\\", "\\"))
+            })
+}
+
+// a star for every part with an answer, recorded or not
+pub fn stars(expected: &[Expected]) -> usize {
+    expected.len()
+}
+
+// newest day first. a day with only one part solved says so after its description
+pub fn table(entries: &[Entry], expected: &[Expected]) -> String {
+    let rows: Vec<(String, String, String)> = entries.iter().rev().map(|entry| {
+        let solved = |part| answers::find(expected, entry.day, part).is_some();
+
+        let note = match (solved(1), solved(2)) {
+            (true,  true ) => "",
+            (true,  false) => "(part 1)",
+            (false, _    ) => "(unsolved)"
+        };
+
+        let description = [entry.description.as_str(), note].into_iter()
+                                                             .filter(|s| !s.is_empty())
+                                                             .collect::<Vec<_>>()
+                                                             .join(" ");
+
+        (entry.day.to_string(),
+         format!("[{}](./days/day_{:02}/src/lib.rs)", entry.title, entry.day),
+         description)
+    }).collect();
+
+    let width = |header: &str, column: fn(&(String, String, String)) -> &String| {
+        rows.iter().map(|row| column(row).chars().count()).chain([header.len()]).max().unwrap()
+    };
+
+    let day   = width("Day",         |row| &row.0);
+    let title = width("Title",       |row| &row.1);
+    let desc  = width("Description", |row| &row.2);
+
+    let mut table = String::new();
+    table += &format!("| {:<day$} | {:<title$} | {:<desc$} |\n", "Day", "Title", "Description");
+    table += &format!("| {:-<day$} | {:-<title$} | {:-<desc$} |\n", "", "", "");
+
+    for (number, link, description) in &rows {
+        table += &format!("| {:<day$} | {:<title$} | {:<desc$} |\n", number, link, description);
+    }
+
+    table
+}
+
+// swap the new table in between the markers, and the star count into this year's row
+// of the past years table
+pub fn render(readme: &str, table: &str, stars: usize) -> Result<String, String> {
+    let (before, rest) = readme.split_once(&format!("{}\n", BEGIN))
+                               .ok_or_else(|| format!("the README has no {} line", BEGIN))?;
+
+    let (_, after) = rest.split_once(END)
+                         .ok_or_else(|| format!("the README has no {} line", END))?;
+
+    let year_row = format!("| [{}](", crate::YEAR);
+
+    let after: Vec<String> = after.split('\n').map(|line| {
+        if !line.starts_with(&year_row) { return line.to_string() }
+
+        // keep the stars cell as wide as it was
+        let mut cells: Vec<String> = line.split('|').map(str::to_string).collect();
+        if let Some(cell) = cells.get_mut(3) {
+            let width = cell.chars().count().saturating_sub(2);
+            *cell = format!(" {:<width$} ", stars);
+        }
+
+        cells.join("|")
+    }).collect();
+
+    Ok(format!("{}{}\n{}{}{}", before, BEGIN, table, END, after.join("\n")))
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(day: u32, title: &str, description: &str) -> Entry {
+        Entry { day, title: title.to_string(), description: description.to_string() }
+    }
+
+    fn solved(day: u32, part: u32) -> Expected {
+        Expected { day, part, answer: None }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let manifest = "[package]\n\
+                        name = \"day_25\"\n\
+                        \n\
+                        [package.metadata.aoc]\n\
+                        title = \"Full of \\\"Hot\\\" Air\"\n\
+                        description = \"\"\n\
+                        \n\
+                        [dependencies]\n\
+                        title = \"not this one\"\n";
+
+        assert_eq!(metadata(manifest, "title").unwrap(), "Full of \"Hot\" Air");
+        assert_eq!(metadata(manifest, "description").unwrap(), "");
+        assert_eq!(metadata("[package]\ntitle = \"x\"\n", "title"), None);
+    }
+
+    #[test]
+    fn test_table() {
+        let entries  = vec![entry(1, "Calorie Counting", "Count calorie totals"),
+                            entry(2, "Rock Paper Scissors", "Rock paper scissors game"),
+                            entry(3, "Rucksack Reorganization", "Find common items")];
+
+        let expected = vec![solved(1, 1), solved(1, 2), solved(2, 1)];
+
+        assert_eq!(table(&entries, &expected),
+                   "| Day | Title                                               | Description                       |\n\
+                    | --- | --------------------------------------------------- | --------------------------------- |\n\
+                    | 3   | [Rucksack Reorganization](./days/day_03/src/lib.rs) | Find common items (unsolved)      |\n\
+                    | 2   | [Rock Paper Scissors](./days/day_02/src/lib.rs)     | Rock paper scissors game (part 1) |\n\
+                    | 1   | [Calorie Counting](./days/day_01/src/lib.rs)        | Count calorie totals              |\n");
+
+        assert_eq!(stars(&expected), 3);
+    }
+
+    #[test]
+    fn test_render() {
+        let readme = format!("intro\n\n{}\n| old |\n{}\n\n\
+                              | Year                | Language | Stars (50) |\n\
+                              | [2022](./aoc-2022)  | Rust     | 45         |\n\
+                              | [2021](./aoc-2021)  | C#       | 38         |\n", BEGIN, END);
+
+        assert_eq!(render(&readme, "| new |\n", 46).unwrap(),
+                   format!("intro\n\n{}\n| new |\n{}\n\n\
+                            | Year                | Language | Stars (50) |\n\
+                            | [2022](./aoc-2022)  | Rust     | 46         |\n\
+                            | [2021](./aoc-2021)  | C#       | 38         |\n", BEGIN, END));
+
+        assert!(render("no markers", "", 0).is_err());
+    }
+
+    // the same thing `aoc readme --check` does
+    #[test]
+    fn test_readme_is_current() {
+        let root = crate::workspace_root();
+        let readme = std::fs::read_to_string(root.join("README.md")).unwrap();
+
+        assert!(generate(&root).unwrap() == readme, "README.md is stale, run aoc readme");
+    }
+}
@@ -0,0 +1,172 @@
+use crate::run::Run;
+use common::Answer;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+// how `aoc run` prints its results: the table for people, or one record per part for
+// anything that wants to read them back in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv"  => Ok(Format::Csv),
+            _      => Err(format!("expected text, json or csv, got \"{}\"", s))
+        }
+    }
+}
+
+// one part of one day. the parse time is the day's, so it's the same for both its parts
+pub struct Record {
+    pub day   : u32,
+    pub part  : u32,
+    pub answer: Answer,
+    pub parse : Duration,
+    pub solve : Duration
+}
+
+impl Record {
+    pub fn from_run(day: u32, run: &Run) -> Vec<Record> {
+        run.parts.iter()
+                 .map(|part| Record {
+                     day,
+                     part  : part.part,
+                     answer: part.answer.clone(),
+                     parse : run.parse,
+                     solve : part.time
+                 })
+                 .collect()
+    }
+
+    fn kind(&self) -> &'static str {
+        match self.answer {
+            Answer::Number(_)   => "number",
+            Answer::Text(_)     => "text",
+            Answer::Unsolved(_) => "unsolved"
+        }
+    }
+}
+
+
+/* JSON */
+
+// an array of objects, one to a line:
+//
+//     {"day": 1, "part": 1, "answer": 69626, "type": "number", "parse_ns": 81200, "solve_ns": 3100}
+//
+// number answers are JSON numbers and text answers are strings. a part that couldn't be
+// solved has the reason why as its answer
+pub fn json(records: &[Record]) -> String {
+    if records.is_empty() { return "[]\n".to_string() }
+
+    let objects: Vec<String> = records.iter().map(json_object).collect();
+
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+fn json_object(record: &Record) -> String {
+    let answer = match &record.answer {
+        Answer::Number(n)                     => n.to_string(),
+        Answer::Text(s) | Answer::Unsolved(s) => json_string(s)
+    };
+
+    format!("  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"type\": \"{}\", \"parse_ns\": {}, \"solve_ns\": {}}}",
+            record.day, record.part, answer, record.kind(), record.parse.as_nanos(), record.solve.as_nanos())
+}
+
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"'                 => quoted += "\\\"",
+            '\\'                => quoted += "\\\\",
+            '\n'                => quoted += "\\n",
+            '\t'                => quoted += "\\t",
+            c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c                   => quoted.push(c)
+        }
+    }
+
+    quoted + "\""
+}
+
+
+/* CSV */
+
+// a header line then a line per part, with the same columns as the JSON
+pub fn csv(records: &[Record]) -> String {
+    let mut csv = String::from("day,part,answer,type,parse_ns,solve_ns\n");
+
+    for record in records {
+        writeln!(csv, "{},{},{},{},{},{}",
+                 record.day, record.part, csv_field(&record.answer.to_string()), record.kind(),
+                 record.parse.as_nanos(), record.solve.as_nanos()).unwrap();
+    }
+
+    csv
+}
+
+// quoted if it has to be, with any quotes inside doubled. day 10's answer keeps its line
+// breaks inside the quotes
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record { day: 1,  part: 1, answer: Answer::Number(69626),
+                     parse: Duration::from_nanos(81200), solve: Duration::from_nanos(3100) },
+            Record { day: 10, part: 2, answer: Answer::Text("#..#\n#\"x\"".to_string()),
+                     parse: Duration::from_micros(5), solve: Duration::from_millis(1) }
+        ]
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(json(&records()),
+                   "[\n  \
+                    {\"day\": 1, \"part\": 1, \"answer\": 69626, \"type\": \"number\", \"parse_ns\": 81200, \"solve_ns\": 3100},\n  \
+                    {\"day\": 10, \"part\": 2, \"answer\": \"#..#\\n#\\\"x\\\"\", \"type\": \"text\", \"parse_ns\": 5000, \"solve_ns\": 1000000}\n\
+                    ]\n");
+
+        assert_eq!(json(&[]), "[]\n");
+        assert_eq!(json_string("a\u{1}"), "\"a\\u0001\"");
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(csv(&records()),
+                   "day,part,answer,type,parse_ns,solve_ns\n\
+                    1,1,69626,number,81200,3100\n\
+                    10,2,\"#..#\n#\"\"x\"\"\",text,5000,1000000\n");
+    }
+}
@@ -0,0 +1,114 @@
+use common::{Answer, ParseError, Solution};
+use std::time::{Duration, Instant};
+
+// the results of parsing a day's input and running some of its parts
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>
+}
+
+pub struct PartRun {
+    pub part  : u32,
+    pub answer: Answer,
+    pub time  : Duration
+}
+
+// parse the input then time each requested part separately
+pub fn solve<S: Solution>(contents: &str,
+                          parts   : &[u32]) -> Result<Run, ParseError>
+{
+    let (input, parse) = timed(|| S::load(contents));
+    let input = input?;
+
+    let parts = parts.iter()
+                     .map(|&part| {
+                         let (answer, time) = match part {
+                             1 => timed(|| S::part1(&input)),
+                             2 => timed(|| S::part2(&input)),
+                             _ => panic!("there are only two parts to a day")
+                         };
+
+                         PartRun { part, answer, time }
+                     })
+                     .collect();
+
+    Ok(Run { parse, parts })
+}
+
+// run a function and measure how long it took
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+// 850ns, 12.3µs, 4.56ms, 2.653s
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    match nanos {
+        0..=999                 => format!("{}ns", nanos),
+        1_000..=999_999         => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _                       => format!("{:.3}s" , nanos as f64 / 1e9)
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse::Line;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(2_653)), "2.653s");
+    }
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const DAY: u32 = 0;
+
+        type Input = Vec<u32>;
+
+        fn parse(s: &str) -> Result<Vec<u32>, ParseError> {
+            let line = Line::new(s);
+            let mut scanner = line.scanner();
+            let mut numbers = vec![scanner.number()?];
+
+            while !scanner.is_done() {
+                scanner.tag(" ")?;
+                numbers.push(scanner.number()?);
+            }
+
+            Ok(numbers)
+        }
+
+        fn part1(input: &Vec<u32>) -> Answer { input.iter().sum::<u32>().into() }
+        fn part2(input: &Vec<u32>) -> Answer { format!("{:?}", input).into() }
+    }
+
+    #[test]
+    fn test_solve() {
+        let run = solve::<Numbers>("1 2 3", &[1, 2]).unwrap();
+
+        assert_eq!(run.parts.len(), 2);
+        assert_eq!(run.parts[0].answer, Answer::Number(6));
+        assert_eq!(run.parts[1].answer, Answer::Text("[1, 2, 3]".to_string()));
+    }
+
+    #[test]
+    fn test_solve_bad_input() {
+        let error = solve::<Numbers>("1 two 3", &[1]).err().unwrap();
+
+        assert_eq!(error.day, Some(0));
+        assert_eq!(error.column, 3);
+    }
+}
@@ -0,0 +1,245 @@
+// `aoc new`: a fresh day crate made from template.rs, hooked into the workspace, the
+// runner and the README's table of days
+
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../../template.rs");
+
+// the files written and edited, relative to the workspace root
+pub struct Created {
+    pub written: Vec<PathBuf>,
+    pub edited : Vec<PathBuf>
+}
+
+pub fn create(root: &Path, day: u32, title: &str) -> Result<Created, String> {
+    let dir = root.join(format!("days/day_{:02}", day));
+    if dir.exists() { return Err(format!("{} already exists", dir.display())) }
+
+    // make every edit in memory first, the README's included, so a file that won't take
+    // one leaves nothing half done. only a write that fails partway through can do that
+    let edits = [
+        ("Cargo.toml",      add_member     as fn(&str, u32, &str) -> Option<String>),
+        ("aoc/Cargo.toml",  add_dependency),
+        ("aoc/src/days.rs", add_day)
+    ];
+
+    let mut edited = vec![];
+
+    for (file, edit) in edits {
+        let path = root.join(file);
+        let contents = std::fs::read_to_string(&path)
+                               .map_err(|error| format!("couldn't read {}: {}", path.display(), error))?;
+
+        let contents = edit(&contents, day, title).ok_or_else(|| format!("couldn't find where day {} goes in {}", day, file))?;
+        edited.push((PathBuf::from(file), contents));
+    }
+
+    // the README's table is built from the day manifests, so the new day is added to it
+    // as its manifest will be
+    let entry = crate::readme::Entry { day, title: title.to_string(), description: String::new() };
+    edited.push((PathBuf::from("README.md"), crate::readme::generate_with(root, Some(entry))?));
+
+    let written = vec![
+        (PathBuf::from(format!("days/day_{:02}/Cargo.toml", day)),  cargo_toml(day, title)),
+        (PathBuf::from(format!("days/day_{:02}/src/lib.rs", day)),  lib_rs(day)),
+        (PathBuf::from(format!("days/day_{:02}/src/main.rs", day)), main_rs(day)),
+        (PathBuf::from(format!("days/day_{:02}/example.txt", day)), String::new())
+    ];
+
+    let write = |(file, contents): &(PathBuf, String)| -> Result<(), String> {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| std::fs::write(&path, contents))
+            .map_err(|error| format!("couldn't write {}: {}", path.display(), error))
+    };
+
+    written.iter().chain(edited.iter()).try_for_each(write)?;
+
+    Ok(Created {
+        written: written.into_iter().map(|(file, _)| file).collect(),
+        edited : edited.into_iter().map(|(file, _)| file).collect()
+    })
+}
+
+fn url(day: u32) -> String {
+    format!("https://adventofcode.com/{}/day/{}", crate::YEAR, day)
+}
+
+// the description is left for filling in by hand
+fn cargo_toml(day: u32, title: &str) -> String {
+    format!("[package]\n\
+             name = \"day_{:02}\"\n\
+             version = \"0.1.0\"\n\
+             edition = \"2021\"\n\
+             \n\
+             # See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html\n\
+             \n\
+             [package.metadata.aoc]\n\
+             title = \"{}\"\n\
+             description = \"\"\n\
+             \n\
+             [dependencies]\n\
+             common = {{ path = \"../../libs/common\" }}\n", day, title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn main_rs(day: u32) -> String {
+    format!("/*  {}  */\n\
+             \n\
+             fn main() {{\n    \
+                 common::main::<day_{:02}::Day{:02}>(env!(\"CARGO_MANIFEST_DIR\"));\n\
+             }}\n", url(day), day, day)
+}
+
+// the template with its placeholders filled in: the url, the day number and the struct name
+fn lib_rs(day: u32) -> String {
+    TEMPLATE.replace("https://adventofcode.com/2022/day/i", &url(day))
+            .replace("const DAY: u32 = i;", &format!("const DAY: u32 = {};", day))
+            .replace("DayNN", &format!("Day{:02}", day))
+}
+
+
+/* Edits */
+
+// each of these finds the last line for an earlier day and puts the new day's line after it
+
+fn insert_after_last(contents: &str, is_day_line: impl Fn(&str) -> bool, line: String) -> Option<String> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let last = lines.iter().rposition(|l| is_day_line(l))?;
+
+    let mut edited: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    edited.insert(last + 1, line);
+
+    Some(edited.join("\n") + "\n")
+}
+
+// the number in a line mentioning day_NN, if it has one
+fn day_in(line: &str) -> Option<u32> {
+    let start = line.find("day_")? + 4;
+    let digits: String = line[start..].chars().take_while(char::is_ascii_digit).collect();
+
+    digits.parse().ok()
+}
+
+fn add_member(manifest: &str, day: u32, _title: &str) -> Option<String> {
+    insert_after_last(manifest,
+                      |l| l.trim().starts_with("\"days/day_") && day_in(l) < Some(day),
+                      format!("    \"days/day_{:02}\",", day))
+}
+
+fn add_dependency(manifest: &str, day: u32, _title: &str) -> Option<String> {
+    insert_after_last(manifest,
+                      |l| l.starts_with("day_") && day_in(l) < Some(day),
+                      format!("day_{:02} = {{ path = \"../days/day_{:02}\" }}", day, day))
+}
+
+fn add_day(days: &str, day: u32, _title: &str) -> Option<String> {
+    insert_after_last(days,
+                      |l| l.trim().starts_with("Day { number:") && day_in(l) < Some(day),
+                      format!("        Day {{ number: {:>2}, solve: solve::<day_{:02}::Day{:02}> }},", day, day, day))
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_lib_rs() {
+        let lib = lib_rs(25);
+
+        assert!(lib.starts_with("/*  https://adventofcode.com/2022/day/25  */\n"));
+        assert!(lib.contains("pub struct Day25;"));
+        assert!(lib.contains("impl Solution for Day25 {"));
+        assert!(lib.contains("const DAY: u32 = 25;"));
+
+        // the example's tests wait for its answers
+        assert!(!lib.contains("fn test_()"));
+        assert!(lib.contains("#[ignore = \"needs the example's answer\"]\n    fn test_part1()"));
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "members = [\n    \"aoc\",\n    \"days/day_01\",\n    \"days/day_24\",\n]\n";
+
+        assert_eq!(add_member(manifest, 25, "").unwrap(),
+                   "members = [\n    \"aoc\",\n    \"days/day_01\",\n    \"days/day_24\",\n    \"days/day_25\",\n]\n");
+
+        // a gap gets filled in order
+        assert_eq!(add_member(manifest, 2, "").unwrap(),
+                   "members = [\n    \"aoc\",\n    \"days/day_01\",\n    \"days/day_02\",\n    \"days/day_24\",\n]\n");
+    }
+
+    #[test]
+    fn test_add_day() {
+        let days = "    vec![\n        Day { number: 24, solve: solve::<day_24::Day24> },\n    ]\n";
+
+        assert_eq!(add_day(days, 25, "").unwrap(),
+                   "    vec![\n        Day { number: 24, solve: solve::<day_24::Day24> },\n        \
+                            Day { number: 25, solve: solve::<day_25::Day25> },\n    ]\n");
+    }
+
+    // the files create() reads, copied from the workspace
+    fn workspace_copy(name: &str) -> PathBuf {
+        let root = temp_dir(name);
+
+        let days = (1..=24).map(|day| format!("days/day_{:02}/Cargo.toml", day));
+        let files = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs", "README.md", "answers.txt"].map(String::from);
+
+        for file in files.into_iter().chain(days) {
+            let path = root.join(&file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::copy(crate::workspace_root().join(&file), path).unwrap();
+        }
+
+        root
+    }
+
+    #[test]
+    fn test_create() {
+        let root = workspace_copy("scaffold");
+
+        let created = create(&root, 25, "Full of Hot Air").unwrap();
+        assert_eq!(created.written.len(), 4);
+        assert_eq!(created.edited.len(), 4);
+
+        let read = |file: &str| std::fs::read_to_string(root.join(file)).unwrap();
+
+        assert!(read("days/day_25/src/main.rs").contains("common::main::<day_25::Day25>(env!(\"CARGO_MANIFEST_DIR\"));"));
+        assert!(read("days/day_25/Cargo.toml").contains("name = \"day_25\""));
+        assert!(read("days/day_25/Cargo.toml").contains("title = \"Full of Hot Air\""));
+        assert_eq!(read("days/day_25/example.txt"), "");
+
+        assert!(read("Cargo.toml").contains("    \"days/day_24\",\n    \"days/day_25\",\n"));
+        assert!(read("aoc/Cargo.toml").contains("day_25 = { path = \"../days/day_25\" }"));
+        assert!(read("aoc/src/days.rs").contains("solve::<day_25::Day25>"));
+        assert!(read("README.md").contains("| 25  | [Full of Hot Air](./days/day_25/src/lib.rs)          | (unsolved)"));
+
+        // and not twice
+        assert!(create(&root, 25, "Full of Hot Air").err().unwrap().ends_with("already exists"));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_create_nothing() {
+        let root = workspace_copy("scaffold-nothing");
+        let manifest = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+
+        // a README with nowhere to put the table stops it before anything's written
+        std::fs::write(root.join("README.md"), "no table\n").unwrap();
+
+        assert!(create(&root, 25, "Full of Hot Air").err().unwrap().starts_with("the README has no"));
+        assert!(!root.join("days/day_25").exists());
+        assert_eq!(std::fs::read_to_string(root.join("Cargo.toml")).unwrap(), manifest);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,76 @@
+// a tiny HTTP server for tests to point base_url at. it hands every request to a
+// closure to decide the status and body, and remembers the raw requests it saw
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+pub struct Stub {
+    pub url : String,
+    requests: Arc<Mutex<Vec<String>>>
+}
+
+impl Stub {
+    pub fn start(respond: impl Fn(&str) -> (u16, String) + Send + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let seen = Arc::clone(&requests);
+
+        // the thread is left to die with the test process
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = read_request(&mut BufReader::new(&stream));
+
+                let (status, body) = respond(&request);
+                seen.lock().unwrap().push(request);
+
+                write!(stream,
+                       "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                       status,
+                       body.len(),
+                       body).unwrap();
+            }
+        });
+
+        Stub { url, requests }
+    }
+
+    // the same response to everything
+    pub fn serving(status: u16, body: &str) -> Self {
+        let body = body.to_string();
+        Stub::start(move |_| (status, body.clone()))
+    }
+
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+// the request line and headers, then as much body as Content-Length says
+fn read_request(reader: &mut impl BufRead) -> String {
+    let mut request = String::new();
+    let mut length = 0;
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().unwrap();
+            }
+        }
+
+        request.push_str(&line);
+        if line == "\r\n" || line.is_empty() { break }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    request.push_str(&String::from_utf8_lossy(&body));
+
+    request
+}
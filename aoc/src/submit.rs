@@ -0,0 +1,367 @@
+use crate::client::{Client, ClientError};
+use crate::config::Config;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+// what the site said about an answer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+
+    // anything else, like "You don't seem to be solving the right level"
+    Unknown
+}
+
+impl Verdict {
+    // the response is a whole html page, the verdict is in its <article>
+    pub fn from_response(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") { return Verdict::Right       }
+        if text.contains("too recently")            { return Verdict::RateLimited }
+
+        if text.contains("That's not the right answer") {
+            return if      text.contains("your answer is too high") { Verdict::TooHigh }
+                   else if text.contains("your answer is too low")  { Verdict::TooLow  }
+                   else                                             { Verdict::Wrong   }
+        }
+
+        Verdict::Unknown
+    }
+
+    // the answer itself was wrong, as opposed to not getting a say on it
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Right       => "right",
+            Verdict::Wrong       => "wrong",
+            Verdict::TooHigh     => "too-high",
+            Verdict::TooLow      => "too-low",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::Unknown     => "unknown"
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [Verdict::Right, Verdict::Wrong, Verdict::TooHigh, Verdict::TooLow, Verdict::RateLimited, Verdict::Unknown]
+            .into_iter()
+            .find(|verdict| verdict.name() == name)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// the text of the page's <article> with the tags stripped out, which is the message
+// the site shows after a submission
+pub fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _                                       => html
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+             _  => if !in_tag { text.push(c) }
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+
+/* Ledger */
+
+// every answer ever submitted, one per line in .aoc/submissions.txt:
+//
+//     year  day  part  verdict  answer
+//     2022  16   1     too-low  1998
+//
+// tab separated, with the answer last since it's the only field that could hold anything
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    pub year   : u32,
+    pub day    : u32,
+    pub part   : u32,
+    pub verdict: Verdict,
+    pub answer : String
+}
+
+pub struct Ledger {
+    path    : PathBuf,
+    attempts: Vec<Attempt>
+}
+
+impl Ledger {
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join("submissions.txt");
+
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(format!("couldn't read {}: {}", path.display(), error))
+        };
+
+        let attempts = contents.lines()
+                               .enumerate()
+                               .map(|(number, line)| {
+                                   Attempt::from_line(line).ok_or_else(|| {
+                                       format!("{} line {}: expected year, day, part, verdict and answer",
+                                               path.display(),
+                                               number + 1)
+                                   })
+                               })
+                               .collect::<Result<_, _>>()?;
+
+        Ok(Ledger { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    // record an attempt, appending it to the file straight away
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        use std::io::Write;
+
+        let append = || -> std::io::Result<()> {
+            std::fs::create_dir_all(self.path.parent().unwrap())?;
+
+            let mut file = std::fs::OpenOptions::new().create(true)
+                                                      .append(true)
+                                                      .open(&self.path)?;
+            writeln!(file, "{}", attempt.to_line())
+        };
+
+        append().map_err(|error| format!("couldn't write {}: {}", self.path.display(), error))?;
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+
+    // decide from earlier attempts whether an answer is worth sending
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<(), Refusal> {
+        let earlier = self.attempts.iter()
+                                   .filter(|a| a.year == year && a.day == day && a.part == part);
+
+        for attempt in earlier {
+            if attempt.verdict == Verdict::Right {
+                return Err(Refusal::AlreadyRight(attempt.answer.clone()))
+            }
+
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                return Err(Refusal::KnownWrong(attempt.verdict))
+            }
+
+            // a number past a bound we've already been given can't be right either
+            if let (Ok(answer), Ok(bound)) = (answer.parse::<i64>(), attempt.answer.parse::<i64>()) {
+                if attempt.verdict == Verdict::TooHigh && answer > bound { return Err(Refusal::Beyond(attempt.clone())) }
+                if attempt.verdict == Verdict::TooLow  && answer < bound { return Err(Refusal::Beyond(attempt.clone())) }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Attempt {
+    fn to_line(&self) -> String {
+        format!("{}\t{}\t{}\t{}\t{}", self.year, self.day, self.part, self.verdict, self.answer)
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(5, '\t');
+
+        Some(Attempt {
+            year   : fields.next()?.parse().ok()?,
+            day    : fields.next()?.parse().ok()?,
+            part   : fields.next()?.parse().ok()?,
+            verdict: Verdict::from_name(fields.next()?)?,
+            answer : fields.next()?.to_string()
+        })
+    }
+}
+
+
+/* Submitting */
+
+// reasons not to send an answer at all
+#[derive(Debug, Eq, PartialEq)]
+pub enum Refusal {
+    AlreadyRight(String),
+    KnownWrong(Verdict),
+    Beyond(Attempt)
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadyRight(answer) => write!(f, "already solved, the answer was {}", answer),
+            Refusal::KnownWrong(verdict)  => write!(f, "already submitted, it was {}", verdict),
+            Refusal::Beyond(attempt)      => write!(f, "{} was already {}", attempt.answer, attempt.verdict)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    Ledger(String)
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitting: {}", refusal),
+            SubmitError::Client(error)    => write!(f, "{}", error),
+            SubmitError::Ledger(error)    => write!(f, "{}", error)
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+// the verdict along with the site's message about it
+pub struct Submitted {
+    pub verdict: Verdict,
+    pub message: String
+}
+
+// send an answer unless the ledger already knows how it will go, then record the verdict
+pub fn submit(config: &Config,
+              dir   : &Path,
+              year  : u32,
+              day   : u32,
+              part  : u32,
+              answer: &str) -> Result<Submitted, SubmitError>
+{
+    let mut ledger = Ledger::load(dir).map_err(SubmitError::Ledger)?;
+    ledger.check(year, day, part, answer).map_err(SubmitError::Refused)?;
+
+    let client = Client::new(config).map_err(SubmitError::Client)?;
+    let level  = part.to_string();
+    let html   = client.post(&format!("/{}/day/{}/answer", year, day),
+                             &[("level", &level), ("answer", answer)])
+                       .map_err(SubmitError::Client)?;
+
+    let verdict = Verdict::from_response(&html);
+
+    ledger.record(Attempt { year, day, part, verdict, answer: answer.to_string() })
+          .map_err(SubmitError::Ledger)?;
+
+    Ok(Submitted { verdict, message: article_text(&html) })
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", message)
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        let right     = page("That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.");
+        let too_high  = page("That's not the right answer; your answer is too high. If you're stuck, ...");
+        let too_low   = page("That's not the right answer; your answer is too low. If you're stuck, ...");
+        let wrong     = page("That's not the right answer. If you're stuck, make sure you're using the full input data");
+        let limited   = page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 41s left to wait.");
+        let completed = page("You don't seem to be solving the right level. Did you already complete it?");
+
+        assert_eq!(Verdict::from_response(&right)    , Verdict::Right);
+        assert_eq!(Verdict::from_response(&too_high) , Verdict::TooHigh);
+        assert_eq!(Verdict::from_response(&too_low)  , Verdict::TooLow);
+        assert_eq!(Verdict::from_response(&wrong)    , Verdict::Wrong);
+        assert_eq!(Verdict::from_response(&limited)  , Verdict::RateLimited);
+        assert_eq!(Verdict::from_response(&completed), Verdict::Unknown);
+
+        assert_eq!(article_text(&right), "That's the right answer! You are one gold star closer.");
+    }
+
+    #[test]
+    fn test_attempt_line() {
+        let attempt = Attempt { year: 2022, day: 16, part: 1, verdict: Verdict::TooLow, answer: "1998".to_string() };
+
+        assert_eq!(attempt.to_line(), "2022\t16\t1\ttoo-low\t1998");
+        assert_eq!(Attempt::from_line(&attempt.to_line()), Some(attempt));
+        assert_eq!(Attempt::from_line("2022\t16\t1\tmaybe\t1998"), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let attempt = |part, verdict, answer: &str| Attempt { year: 2022, day: 16, part, verdict, answer: answer.to_string() };
+
+        let ledger = Ledger {
+            path: PathBuf::new(),
+            attempts: vec![attempt(1, Verdict::TooHigh    , "2500"),
+                           attempt(1, Verdict::TooLow     , "1998"),
+                           attempt(1, Verdict::RateLimited, "2119"),
+                           attempt(2, Verdict::Right      , "2615")]
+        };
+
+        assert_eq!(ledger.check(2022, 16, 1, "2500"), Err(Refusal::KnownWrong(Verdict::TooHigh)));
+        assert_eq!(ledger.check(2022, 16, 1, "3000"), Err(Refusal::Beyond(attempt(1, Verdict::TooHigh, "2500"))));
+        assert_eq!(ledger.check(2022, 16, 1, "1000"), Err(Refusal::Beyond(attempt(1, Verdict::TooLow , "1998"))));
+        assert_eq!(ledger.check(2022, 16, 2, "2616"), Err(Refusal::AlreadyRight("2615".to_string())));
+
+        // being rate limited says nothing about the answer
+        assert_eq!(ledger.check(2022, 16, 1, "2119"), Ok(()));
+        assert_eq!(ledger.check(2022, 17, 1, "2500"), Ok(()));
+    }
+
+    #[test]
+    fn test_submit() {
+        let stub = Stub::start(|request| {
+            if request.ends_with("answer=42") { (200, page("That's the right answer!")) }
+            else                              { (200, page("That's not the right answer; your answer is too low.")) }
+        });
+
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let config = Config { session: Some("abc123".to_string()), base_url: stub.url.clone() };
+
+        let submitted = submit(&config, &dir, 2022, 1, 1, "41").unwrap();
+        assert_eq!(submitted.verdict, Verdict::TooLow);
+
+        let requests = stub.requests();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=41"));
+
+        // the wrong answer isn't sent again, and neither is anything lower
+        assert!(matches!(submit(&config, &dir, 2022, 1, 1, "41"), Err(SubmitError::Refused(_))));
+        assert!(matches!(submit(&config, &dir, 2022, 1, 1, "40"), Err(SubmitError::Refused(_))));
+        assert_eq!(stub.requests().len(), 1);
+
+        assert_eq!(submit(&config, &dir, 2022, 1, 1, "42").unwrap().verdict, Verdict::Right);
+
+        // both attempts made it to the file
+        let ledger = Ledger::load(&dir).unwrap();
+        assert_eq!(ledger.attempts().len(), 2);
+        assert_eq!(ledger.attempts()[1].verdict, Verdict::Right);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
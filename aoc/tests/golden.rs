@@ -0,0 +1,59 @@
+// every day against its real input, checked against the answers recorded in answers.txt.
+// inputs aren't checked in, so a day whose input hasn't been fetched is skipped. it says
+// which ones it skipped, and with no inputs at all there's nothing checked and it fails,
+// so it's ignored unless asked for
+
+use aoc::answers::{self, Expected};
+use aoc::days;
+
+#[test]
+#[ignore = "needs the puzzle inputs, see aoc fetch"]
+fn golden() {
+    let expected = answers::load().unwrap_or_else(|error| panic!("{}", error));
+    let mut mismatches = vec![];
+    let mut checked    = vec![];
+    let mut skipped    = vec![];
+
+    for day in days::all() {
+        let parts: Vec<u32> = expected.iter()
+                                      .filter(|e| e.day == day.number && e.answer.is_some())
+                                      .map(|e| e.part)
+                                      .collect();
+
+        if parts.is_empty() { continue }
+
+        let path = aoc::input_path(day.number);
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            eprintln!("skipping day {}, no input at {}", day.number, path.display());
+            skipped.push(day.number);
+            continue
+        };
+
+        checked.push(day.number);
+
+        let run = match day.solve(&contents, &parts) {
+            Ok(run)    => run,
+            Err(error) => { mismatches.push(error.to_string()); continue }
+        };
+
+        for part in run.parts {
+            let Some(Expected { answer: Some(answer), .. }) = answers::find(&expected, day.number, part.part) else { continue };
+            let actual = part.answer.to_string();
+
+            if &actual != answer {
+                mismatches.push(format!("day {} part {}: expected {}, got {}",
+                                        day.number,
+                                        part.part,
+                                        answers::escape(answer),
+                                        answers::escape(&actual)));
+            }
+        }
+    }
+
+    let list = |days: &[u32]| days.iter().map(u32::to_string).collect::<Vec<_>>().join(", ");
+
+    eprintln!("checked {} days, skipped {}: {}", checked.len(), skipped.len(), list(&skipped));
+
+    assert!(!checked.is_empty(), "no day had an input to check, skipped {}", list(&skipped));
+    assert!(mismatches.is_empty(), "\n{}", mismatches.join("\n"));
+}
@@ -0,0 +1,118 @@
+/*  https://adventofcode.com/2022/day/1  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{blocks, Line};
+
+mod report;
+mod stream;
+
+pub use report::{Bucket, Report};
+pub use stream::{top_elves, Carrier, StreamError};
+use stream::Top;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { elves : Vec<Elf> }
+struct Elf   { cals  : Vec<u32> }
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let elves = blocks(s).iter()
+                             .map(|block| Elf::from_lines(block))
+                             .collect::<Result<_, _>>()?;
+
+        Ok(Input { elves })
+    }
+}
+
+impl Elf {
+    fn from_lines(lines: &[Line]) -> Result<Self, ParseError> {
+        let cals = lines.iter()
+                        .map(Line::number)
+                        .collect::<Result<_, _>>()?;
+
+        Ok(Elf { cals })
+    }
+
+    fn get_total_cals(&self) -> u64 {
+        self.cals.iter().map(|&cals| cals as u64).sum()
+    }
+}
+
+// get the highest total calorie count
+fn part1(input: &Input) -> u64 {
+    sum_highest(1, input)
+}
+
+// sum the 3 highest total calorie counts
+fn part2(input: &Input) -> u64 {
+    sum_highest(3, input)
+}
+
+// only the highest few totals are kept as the elves go by, rather than sorting all of them
+fn sum_highest(count: usize, input: &Input) -> u64 {
+    let mut top = Top::new(count);
+
+    for (index, elf) in input.elves.iter().enumerate() {
+        top.offer(Carrier { elf: index, total: elf.get_total_cals() });
+    }
+
+    top.into_sorted()
+       .iter()
+       .map(|carrier| carrier.total)
+       .sum()
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 24000);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 45000);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = get_example();
+        assert_eq!(input.elves.len(), 5);
+        assert_eq!(input.elves[2].cals, [5000, 6000]);
+
+        let error = Day01::parse("1000
+
+20o0").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+
+    fn get_example() -> Input {
+        Day01::parse(common::example!()).unwrap()
+    }
+}
+
+/*
+    $ cargo run
+    Compiling aoc-2022 v0.1.0 (C:\Users\Jason\Documents\GitHub\aoc-2022\day_01)
+        Finished dev [unoptimized + debuginfo] target(s) in 0.42s
+        Running `target\debug\aoc-2022.exe`
+    Part 1: 69626
+    Part 2: 206780
+*/
@@ -1,73 +1,68 @@
 /*  https://adventofcode.com/2022/day/1  */
 
+use common::{Solution, Source};
+use day_01::Day01;
+use std::path::Path;
+
+// with --top <n> the inventories are streamed instead of loaded, for generated inputs too
+// big to fit in memory, and it prints which elves carry the most. --report <width> prints
+// statistics of the totals instead, with histogram buckets that wide:
+//
+//     $ day_01 --top 3 huge.txt
+//     $ day_01 --top 2 --example
+//     $ day_01 --report 5000 --example
+//
 fn main() {
-    let input = get_input();
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    match args.as_slice() {
+        [flag, count, rest @ ..] if flag == "--top"    => top(count, rest),
+        [flag, width, rest @ ..] if flag == "--report" => report(width, rest),
+        _ => common::main::<Day01>(env!("CARGO_MANIFEST_DIR"))
+    }
 }
 
-struct Input { elves : Vec<Elf> }
-struct Elf   { cals  : Vec<u32> }
-
-impl Input {
-    fn from(input: &str) -> Self {
-        let chunks = input.trim().split("\n\n");
+fn report(width: &str, args: &[String]) {
+    let width = match width.parse() {
+        Ok(width) if width > 0 => width,
+        _ => fail(format!("expected a bucket width after --report, got \"{}\"", width))
+    };
 
-        Input {
-            elves: chunks.map(Elf::from).collect()
-        }
-    }
-}
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let contents = Source::from_args(args, dir).and_then(|source| source.read()).unwrap_or_else(|error| fail(error));
 
-impl Elf {
-    fn from(chunk: &str) -> Self {
-        Elf {
-            cals: chunk.split('\n')
-                       .map(|line| line.parse().unwrap())
-                       .collect()
-        }
-    }
+    let input = Day01::load(&contents).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1)
+    });
 
-    fn get_total_cals(&self) -> u32 {
-        self.cals.iter().sum()
+    match input.report(width) {
+        Ok(report) => println!("{}", report),
+        Err(error) => fail(error)
     }
 }
 
-// get the highest total calorie count
-fn part1(input: &Input) -> u32 {
-    sum_highest(1, input)    
-}
+fn top(count: &str, args: &[String]) {
+    let Ok(count) = count.parse() else {
+        fail(format!("expected a number of elves after --top, got \"{}\"", count))
+    };
 
-// sum the 3 highest total calorie counts
-fn part2(input: &Input) -> u32 {
-    sum_highest(3, input)    
-}
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let reader = Source::from_args(args, dir).and_then(|source| source.open()).unwrap_or_else(|error| fail(error));
 
-fn sum_highest(count: usize, input: &Input) -> u32 {
-    let mut totals: Vec<u32> =
-        input.elves.iter()
-                   .map(|elf| elf.get_total_cals())
-                   .collect();
+    let top = day_01::top_elves(reader, count).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1)
+    });
 
-    totals.sort();
-    totals.iter()
-          .rev()
-          .take(count)
-          .sum()
-}
+    for carrier in &top {
+        println!("Elf {}: {}", carrier.elf, carrier.total);
+    }
 
-fn get_input() -> Input {
-    let input = std::fs::read_to_string("input.txt").expect("Couldn't read input.txt");
-    
-    Input::from(&input)
+    println!("Total: {}", top.iter().map(|carrier| carrier.total).sum::<u64>());
 }
 
-/*
-    $ cargo run
-    Compiling aoc-2022 v0.1.0 (C:\Users\Jason\Documents\GitHub\aoc-2022\day_01)
-        Finished dev [unoptimized + debuginfo] target(s) in 0.42s
-        Running `target\debug\aoc-2022.exe`
-    Part 1: 69626
-    Part 2: 206780
-*/
+fn fail(error: String) -> ! {
+    eprintln!("error: {}", error);
+    std::process::exit(1)
+}
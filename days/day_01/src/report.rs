@@ -0,0 +1,234 @@
+use crate::{Carrier, Input};
+use std::fmt;
+
+// the percentiles of the totals a report lists, the quartiles among them
+const PERCENTILES: [u32; 5] = [10, 25, 50, 75, 90];
+
+// the most rows a histogram gets. a bucket width too narrow for the spread of the totals
+// would otherwise make one for every few calories between the smallest and the biggest
+const MAX_BUCKETS: u64 = 1000;
+
+// descriptive statistics of what the elves are carrying
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+
+    // how many items each elf carries, in the order they're listed
+    pub items: Vec<usize>,
+
+    // of the elves' calorie totals
+    pub min   : u64,
+    pub max   : u64,
+    pub mean  : f64,
+    pub median: f64,
+
+    // (percentile, total) pairs, interpolated between the two nearest totals
+    pub percentiles: Vec<(u32, f64)>,
+
+    // how many totals fall in each bucket, from the lowest bucket with any in to the highest
+    pub histogram: Vec<Bucket>,
+
+    // totals more than one and a half interquartile ranges outside the quartiles, biggest first
+    pub outliers: Vec<Carrier>
+}
+
+// the totals from `from` up to but not including `to`. a bucket that would end past the
+// biggest number there is ends at u64::MAX instead, and includes it
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bucket {
+    pub from : u64,
+    pub to   : u64,
+    pub count: usize
+}
+
+impl Input {
+    // statistics of the calorie totals, with histogram buckets of the given width lined up
+    // on multiples of it. there aren't any statistics of no elves at all, and no histogram
+    // with buckets of no width or with too many of them
+    pub fn report(&self, bucket_width: u64) -> Result<Report, String> {
+        if bucket_width == 0 { return Err("the histogram's buckets need a width".to_string()) }
+
+        let totals: Vec<u64> = self.elves.iter().map(|elf| elf.get_total_cals()).collect();
+
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+
+        let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+            return Err("there are no elves to report on".to_string())
+        };
+
+        let buckets = max / bucket_width - min / bucket_width + 1;
+        if buckets > MAX_BUCKETS {
+            return Err(format!("buckets {} wide make {} of them, more than the {} a histogram can have", bucket_width, buckets, MAX_BUCKETS))
+        }
+
+        let percentiles = PERCENTILES.iter()
+                                     .map(|&p| (p, percentile(&sorted, p)))
+                                     .collect();
+
+        // tukey's fences
+        let (q1, q3) = (percentile(&sorted, 25), percentile(&sorted, 75));
+        let fence    = 1.5 * (q3 - q1);
+
+        let mut outliers: Vec<Carrier> = totals.iter()
+                                               .enumerate()
+                                               .filter(|&(_, &total)| (total as f64) < q1 - fence || (total as f64) > q3 + fence)
+                                               .map(|(elf, &total)| Carrier { elf, total })
+                                               .collect();
+
+        outliers.sort_by_key(|carrier| std::cmp::Reverse(carrier.total));
+
+        Ok(Report {
+            elves : totals.len(),
+            items : self.elves.iter().map(|elf| elf.cals.len()).collect(),
+            min,
+            max,
+            mean  : totals.iter().sum::<u64>() as f64 / totals.len() as f64,
+            median: percentile(&sorted, 50),
+            percentiles,
+            histogram: histogram(&sorted, bucket_width),
+            outliers
+        })
+    }
+}
+
+// linear interpolation between the closest ranks, the same as a spreadsheet's PERCENTILE
+fn percentile(sorted: &[u64], p: u32) -> f64 {
+    let rank  = p as f64 / 100.0 * (sorted.len() - 1) as f64;
+    let below = rank.floor() as usize;
+    let above = rank.ceil() as usize;
+
+    sorted[below] as f64 + (rank - below as f64) * (sorted[above] as f64 - sorted[below] as f64)
+}
+
+// there's at least one total, and report() has checked the buckets they need
+fn histogram(sorted: &[u64], width: u64) -> Vec<Bucket> {
+    let first = sorted[0] / width;
+    let last  = sorted[sorted.len() - 1] / width;
+
+    // n is at most the biggest total over the width, so only the end can overflow
+    let mut buckets: Vec<Bucket> = (first..=last).map(|n| Bucket { from: n * width, to: (n + 1).saturating_mul(width), count: 0 })
+                                                 .collect();
+
+    for total in sorted {
+        buckets[(total / width - first) as usize].count += 1;
+    }
+
+    buckets
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Items: {} ({} to {} each)",
+                 self.items.iter().sum::<usize>(),
+                 self.items.iter().min().unwrap_or(&0),
+                 self.items.iter().max().unwrap_or(&0))?;
+
+        writeln!(f, "Calories: {} to {}, mean {:.1}, median {:.1}", self.min, self.max, self.mean, self.median)?;
+
+        for (p, total) in &self.percentiles {
+            writeln!(f, "  p{:<2} {:>10.1}", p, total)?;
+        }
+
+        // the longest bar is 40 wide, and the rest are scaled to match
+        let most = self.histogram.iter().map(|bucket| bucket.count).max().unwrap_or(1);
+
+        writeln!(f, "Histogram:")?;
+        for bucket in &self.histogram {
+            let row = format!("  {:>8} - {:<8} {:>6} {}",
+                              bucket.from, bucket.to, bucket.count, "#".repeat(bucket.count * 40 / most));
+
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        write!(f, "Outliers:")?;
+        if self.outliers.is_empty() { write!(f, " none")? }
+
+        for carrier in &self.outliers {
+            write!(f, "\n  elf {} with {}", carrier.elf, carrier.total)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use common::Solution;
+
+    // the example's totals, sorted, are 4000, 6000, 10000, 11000 and 24000
+    fn get_example() -> Input {
+        Day01::parse(common::example!()).unwrap()
+    }
+
+    #[test]
+    fn test_report() {
+        let report = get_example().report(5000).unwrap();
+
+        assert_eq!(report.elves, 5);
+        assert_eq!(report.items, [3, 1, 2, 3, 1]);
+        assert_eq!((report.min, report.max), (4000, 24000));
+        assert_eq!(report.mean, 11000.0);
+        assert_eq!(report.median, 10000.0);
+
+        assert_eq!(report.percentiles, [(10, 4800.0), (25, 6000.0), (50, 10000.0), (75, 11000.0), (90, 18800.0)]);
+
+        // only the elf with 24000 is past the upper fence, 11000 + 1.5 * 5000
+        assert_eq!(report.outliers, [Carrier { elf: 3, total: 24000 }]);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let report = get_example().report(5000).unwrap();
+        let counts: Vec<(u64, usize)> = report.histogram.iter().map(|bucket| (bucket.from, bucket.count)).collect();
+
+        // empty buckets in the middle are kept, so the histogram has no gaps
+        assert_eq!(counts, [(0, 1), (5000, 1), (10000, 2), (15000, 0), (20000, 1)]);
+        assert_eq!(report.histogram[4].to, 25000);
+
+        assert_eq!(get_example().report(100_000).unwrap().histogram, [Bucket { from: 0, to: 100_000, count: 5 }]);
+    }
+
+    #[test]
+    fn test_small() {
+        let report = Day01::parse("7").unwrap().report(10).unwrap();
+
+        assert_eq!((report.mean, report.median), (7.0, 7.0));
+        assert!(report.outliers.is_empty());
+
+        assert_eq!(Day01::parse("").unwrap().report(10), Err("there are no elves to report on".to_string()));
+    }
+
+    #[test]
+    fn test_bucket_widths() {
+        assert_eq!(get_example().report(0), Err("the histogram's buckets need a width".to_string()));
+
+        // 4000 to 24000 is buckets 190 to 1142 at 21 wide, but 200 to 1200 at 20 wide
+        assert_eq!(get_example().report(21).unwrap().histogram.len(), 953);
+        assert_eq!(get_example().report(20).err().unwrap(), "buckets 20 wide make 1001 of them, more than the 1000 a histogram can have");
+
+        assert_eq!(get_example().report(u64::MAX).unwrap().histogram, [Bucket { from: 0, to: u64::MAX, count: 5 }]);
+
+        // totals past the width, so the last bucket would end past the biggest u64
+        assert_eq!(histogram(&[7, u64::MAX], u64::MAX),
+                   [Bucket { from: 0, to: u64::MAX, count: 1 }, Bucket { from: u64::MAX, to: u64::MAX, count: 1 }]);
+        assert_eq!(histogram(&[u64::MAX / 2 + 1], u64::MAX / 2 + 1),
+                   [Bucket { from: u64::MAX / 2 + 1, to: u64::MAX, count: 1 }]);
+    }
+
+    #[test]
+    fn test_display() {
+        let text = get_example().report(5000).unwrap().to_string();
+
+        assert!(text.starts_with("Elves: 5\nItems: 10 (1 to 3 each)\nCalories: 4000 to 24000, mean 11000.0, median 10000.0\n"));
+        assert!(text.contains("     10000 - 15000         2 ########################################\n"));
+        assert!(text.contains("     15000 - 20000         0\n"));
+        assert!(text.ends_with("Outliers:\n  elf 3 with 24000"));
+    }
+}
@@ -0,0 +1,205 @@
+use common::ParseError;
+use common::parse::Line;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+
+// one of the elves carrying the most, numbered from 0 in the order they're listed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Carrier {
+    pub elf  : usize,
+    pub total: u64
+}
+
+// the biggest totals offered to it so far, and nothing else. the smallest of them sits on
+// top of a min-heap, so a new total only has to beat that one to get in
+pub struct Top {
+    count: usize,
+
+    // ties go to the elf listed first, so the same input always gives the same elves
+    heap : BinaryHeap<Reverse<(u64, Reverse<usize>)>>
+}
+
+impl Top {
+    pub fn new(count: usize) -> Self {
+        Top { count, heap: BinaryHeap::with_capacity(count + 1) }
+    }
+
+    pub fn offer(&mut self, carrier: Carrier) {
+        self.heap.push(Reverse((carrier.total, Reverse(carrier.elf))));
+
+        if self.heap.len() > self.count { self.heap.pop(); }
+    }
+
+    // biggest total first
+    pub fn into_sorted(self) -> Vec<Carrier> {
+        self.heap.into_sorted_vec()
+                 .into_iter()
+                 .map(|Reverse((total, Reverse(elf)))| Carrier { elf, total })
+                 .collect()
+    }
+}
+
+// why a stream of inventories couldn't be read to the end
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError)
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(error)    => write!(f, "error: couldn't read the input: {}", error),
+            StreamError::Parse(error) => write!(f, "{}", error)
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self { StreamError::Io(error) }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self { StreamError::Parse(error) }
+}
+
+// the longest line worth reading, not counting its line ending. nothing in an inventory
+// comes close, so anything longer is an error rather than a line buffer that grows until
+// it runs out of memory
+const MAX_LINE: u64 = 256;
+
+// the elves carrying the most calories, read a buffer at a time so the input can be far
+// bigger than memory. only the line being parsed and the count biggest totals are kept
+pub fn top_elves(reader: impl Read, count: usize) -> Result<Vec<Carrier>, StreamError> {
+    let mut reader = BufReader::with_capacity(64 * 1024, reader);
+    let mut top    = Top::new(count);
+
+    let mut line   = vec![];
+    let mut number = 0;
+
+    // the elf being added up, if its first item has been read yet
+    let mut elf   = 0;
+    let mut total = None;
+
+    loop {
+        line.clear();
+
+        // room for the longest line and a \r\n after it. a read that fills that without
+        // reaching a \n is still too long once a \r is taken off the end
+        if (&mut reader).take(MAX_LINE + 2).read_until(b'\n', &mut line)? == 0 { break }
+        number += 1;
+
+        if line.last() == Some(&b'\n') { line.pop(); }
+        if line.last() == Some(&b'\r') { line.pop(); }
+
+        let too_long = line.len() as u64 > MAX_LINE;
+
+        let text = String::from_utf8_lossy(&line);
+        let line = Line { number, text: &text };
+
+        if too_long {
+            return Err(line.error(1, format!("a line of at most {} characters", MAX_LINE)).into())
+        }
+
+        // a blank line finishes off the elf before it
+        if line.text.is_empty() {
+            if let Some(total) = total.take() {
+                top.offer(Carrier { elf, total });
+                elf += 1;
+            }
+            continue
+        }
+
+        let cals: u32 = line.number()?;
+        total = Some(total.unwrap_or(0) + cals as u64);
+    }
+
+    if let Some(total) = total {
+        top.offer(Carrier { elf, total });
+    }
+
+    Ok(top.into_sorted())
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top() {
+        let mut top = Top::new(2);
+
+        for (elf, total) in [5, 9, 1, 9, 7].into_iter().enumerate() {
+            top.offer(Carrier { elf, total });
+        }
+
+        // the first of the two 9s is listed first
+        assert_eq!(top.into_sorted(), [Carrier { elf: 1, total: 9 }, Carrier { elf: 3, total: 9 }]);
+        assert!(Top::new(0).into_sorted().is_empty());
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let top = top_elves(common::example!().as_bytes(), 3).unwrap();
+
+        assert_eq!(top, [Carrier { elf: 3, total: 24000 },
+                         Carrier { elf: 2, total: 11000 },
+                         Carrier { elf: 4, total: 10000 }]);
+
+        // windows line endings, extra blank lines and more elves asked for than there are
+        let top = top_elves("1\r\n2\r\n\r\n\r\n\r\n4\r\n".as_bytes(), 5).unwrap();
+        assert_eq!(top, [Carrier { elf: 1, total: 4 }, Carrier { elf: 0, total: 3 }]);
+    }
+
+    #[test]
+    fn test_small_reads() {
+        // a reader that hands over a byte at a time still gets whole lines out
+        struct Trickle<'a>(&'a [u8]);
+
+        impl Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let Some((&byte, rest)) = self.0.split_first() else { return Ok(0) };
+                buf[0] = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+        }
+
+        let top = top_elves(Trickle(common::example!().as_bytes()), 1).unwrap();
+        assert_eq!(top, [Carrier { elf: 3, total: 24000 }]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let Err(StreamError::Parse(error)) = top_elves("100\n\n2x0\n".as_bytes(), 1) else { panic!() };
+        assert_eq!((error.line, error.column), (3, 2));
+
+        let long = "1".repeat(300);
+        let Err(StreamError::Parse(error)) = top_elves(long.as_bytes(), 1) else { panic!() };
+        assert_eq!(error.expected, "a line of at most 256 characters");
+
+        let long = format!("1\n{}\r\n", "1".repeat(257));
+        let Err(StreamError::Parse(error)) = top_elves(long.as_bytes(), 1) else { panic!() };
+        assert_eq!((error.line, error.expected.as_str()), (2, "a line of at most 256 characters"));
+    }
+
+    #[test]
+    fn test_longest_lines() {
+        // 256 characters is fine for the last line, with nothing after it
+        let last = format!("1\n{}7", "0".repeat(255));
+        assert_eq!(top_elves(last.as_bytes(), 1).unwrap(), [Carrier { elf: 0, total: 8 }]);
+
+        // and a \r\n on the end of a line that long doesn't leave a blank line behind
+        for width in [255, 256] {
+            let lines = format!("{0}5\r\n{0}6\r\n", "0".repeat(width - 1));
+            assert_eq!(top_elves(lines.as_bytes(), 2).unwrap(), [Carrier { elf: 0, total: 11 }]);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{Game, Input, Outcome};
+
+// one way of reading our letters, and the score of following the whole guide that way
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Decoding<T> {
+    // each of our letters and what it's taken to mean, in the order the game lists them
+    pub mapping: Vec<(char, T)>,
+    pub total  : u32
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Extremes<T> {
+    pub best : Decoding<T>,
+    pub worst: Decoding<T>
+}
+
+// the best and worst of every way to read our letters as shapes to play, each letter a
+// different shape, and the same as outcomes to aim for. there's no way to read them as
+// outcomes when there are more letters than outcomes, or as shapes when there are more
+// letters than shapes
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Decoded {
+    pub shapes  : Option<Extremes<usize>>,
+    pub outcomes: Option<Extremes<Outcome>>
+}
+
+// try every mapping instead of trusting the one in the rules, which is what the puzzle
+// leaves us guessing at until part 2
+pub fn decode(input: &Input) -> Decoded {
+    let game    = &input.game;
+    let letters = game.our_letters().chars().collect::<Vec<char>>();
+
+    // how many rounds there are of each of their shapes against each of our letters, so
+    // each mapping is scored without going back over the guide
+    let mut counts = vec![vec![0; letters.len()]; game.shape_count()];
+
+    for round in &input.rounds {
+        let letter = letters.iter().position(|&letter| letter == round.us).expect("a letter from the game");
+        counts[round.them][letter] += 1;
+    }
+
+    let shapes: Vec<usize> = (0..game.shape_count()).collect();
+    let outcomes = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    Decoded {
+        shapes  : extremes(&letters, &shapes, |letter, &us, them| game.points(us, them) * counts[them][letter], game),
+        outcomes: extremes(&letters, &outcomes, |letter, &want, them| game.points(game.respond(them, want), them) * counts[them][letter], game)
+    }
+}
+
+// score every mapping of the letters onto different meanings, keeping the first of the
+// highest and the first of the lowest. points(letter, meaning, their shape) is the score
+// of all the rounds of that letter against that shape
+fn extremes<T: Copy>(letters: &[char],
+                     meanings: &[T],
+                     points  : impl Fn(usize, &T, usize) -> u32,
+                     game    : &Game) -> Option<Extremes<T>>
+{
+    let mut extremes: Option<Extremes<T>> = None;
+
+    for arrangement in arrangements(letters.len(), meanings.len()) {
+        let total = arrangement.iter()
+                               .enumerate()
+                               .map(|(letter, &meaning)| (0..game.shape_count()).map(|them| points(letter, &meanings[meaning], them)).sum::<u32>())
+                               .sum();
+
+        let decoding = Decoding {
+            mapping: letters.iter().zip(&arrangement).map(|(&letter, &meaning)| (letter, meanings[meaning])).collect(),
+            total
+        };
+
+        match &mut extremes {
+            None => extremes = Some(Extremes { best: decoding.clone(), worst: decoding }),
+
+            Some(Extremes { best, worst }) => {
+                if total > best.total {
+                    *best = decoding
+                } else if total < worst.total {
+                    *worst = decoding
+                }
+            }
+        }
+    }
+
+    extremes
+}
+
+// every way of picking k different things out of n in order, lowest first: for 2 out of
+// 3, [0, 1], [0, 2], [1, 0], [1, 2], [2, 0], [2, 1]. none when k is more than n
+fn arrangements(k: usize, n: usize) -> Vec<Vec<usize>> {
+    if k == 0 { return vec![vec![]] }
+
+    let mut all = vec![];
+
+    for shorter in arrangements(k - 1, n) {
+        for next in (0..n).filter(|next| !shorter.contains(next)) {
+            all.push([shorter.as_slice(), &[next]].concat());
+        }
+    }
+
+    all
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use common::Solution;
+
+    #[test]
+    fn test_arrangements() {
+        assert_eq!(arrangements(2, 3), [[0, 1], [0, 2], [1, 0], [1, 2], [2, 0], [2, 1]]);
+        assert_eq!(arrangements(3, 3).len(), 6);
+        assert_eq!(arrangements(0, 3), [Vec::<usize>::new()]);
+        assert!(arrangements(4, 3).is_empty());
+    }
+
+    #[test]
+    fn test_decode_example() {
+        let decoded = decode(&Day02::parse(common::example!()).unwrap());
+        let shapes  = decoded.shapes.unwrap();
+
+        // winning every round, or losing every one
+        assert_eq!(shapes.best,  Decoding { mapping: vec![('X', 2), ('Y', 1), ('Z', 0)], total: 24 });
+        assert_eq!(shapes.worst, Decoding { mapping: vec![('X', 0), ('Y', 2), ('Z', 1)], total: 6 });
+
+        // the mapping the puzzle turns out to use for part 2 happens to be the worst
+        let outcomes = decoded.outcomes.unwrap();
+
+        assert_eq!(outcomes.best,  Decoding { mapping: vec![('X', Outcome::Win),  ('Y', Outcome::Lose), ('Z', Outcome::Draw)], total: 18 });
+        assert_eq!(outcomes.worst, Decoding { mapping: vec![('X', Outcome::Lose), ('Y', Outcome::Draw), ('Z', Outcome::Win)],  total: 12 });
+    }
+
+    #[test]
+    fn test_decode_lizard_spock() {
+        let game  = Game::from_string(include_str!("../games/rock_paper_scissors_lizard_spock.txt")).unwrap();
+        let input = Input::with_game("A Y\nE V\nC W\nD Z\nA Y", game).unwrap();
+
+        let decoded = decode(&input);
+
+        // five letters can't be three outcomes each on their own
+        assert_eq!(decoded.outcomes, None);
+
+        // paper twice against rock, spock against scissors, lizard against paper and
+        // scissors against lizard, winning every round. X never comes up, so it's left rock
+        let best = decoded.shapes.unwrap().best;
+
+        assert_eq!(best.total, 9 + 9 + 8 + 10 + 11);
+        assert_eq!(best.mapping, [('V', 1), ('W', 3), ('X', 0), ('Y', 2), ('Z', 4)]);
+    }
+}
@@ -0,0 +1,422 @@
+use common::ParseError;
+use common::parse::{end_of_input, lines, Line, Scanner};
+use std::fmt;
+
+// how a round can go for us
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome { Lose, Draw, Win }
+
+const OUTCOMES: [(&str, Outcome); 3] = [("lose", Outcome::Lose), ("draw", Outcome::Draw), ("win", Outcome::Win)];
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(OUTCOMES[*self as usize].0)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Shape {
+    name  : String,
+    points: u32
+}
+
+// a game like rock paper scissors, described by a table of rules instead of written out
+// in code: the shapes and the points for playing each, which shapes beat which, the points
+// for each outcome, and what the letters in a strategy guide stand for. the rules for
+// playing a shape and for aiming at an outcome both come from the same table
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    shapes  : Vec<Shape>,
+
+    // beats[a][b] when shape a beats shape b
+    beats   : Vec<Vec<bool>>,
+
+    // points for losing, drawing and winning
+    outcomes: [u32; 3],
+
+    // the opponent's letters and the shapes they stand for, then our letters as the
+    // shape to play (part 1) and as the outcome to aim for (part 2)
+    them    : Vec<(char, usize)>,
+    play    : Vec<(char, usize)>,
+    want    : Vec<(char, Outcome)>
+}
+
+impl Game {
+    // the game from the puzzle
+    pub fn rock_paper_scissors() -> Self {
+        Game::from_string(include_str!("../games/rock_paper_scissors.txt")).expect("the built in game is valid")
+    }
+
+    pub fn shape_count(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn shape_name(&self, shape: usize) -> &str {
+        &self.shapes[shape].name
+    }
+
+    pub fn outcome(&self, us: usize, them: usize) -> Outcome {
+        if self.beats[us][them]      { Outcome::Win }
+        else if self.beats[them][us] { Outcome::Lose }
+        else                         { Outcome::Draw }
+    }
+
+    // the points for playing a shape against the opponent's
+    pub fn points(&self, us: usize, them: usize) -> u32 {
+        self.shapes[us].points + self.outcome_points(self.outcome(us, them))
+    }
+
+    pub fn outcome_points(&self, outcome: Outcome) -> u32 {
+        self.outcomes[outcome as usize]
+    }
+
+    // the shape to play against the opponent's to get the outcome. when there's a choice,
+    // as there is in games of more than three shapes, it's the one worth the most points
+    pub fn respond(&self, them: usize, want: Outcome) -> usize {
+        (0..self.shapes.len()).filter(|&us| self.outcome(us, them) == want)
+                              .rev()
+                              .max_by_key(|&us| self.shapes[us].points)
+                              .expect("every shape beats one and loses to one")
+    }
+
+    // the letters that can start and end a line of a strategy guide
+    pub fn their_letters(&self) -> String { self.them.iter().map(|&(letter, _)| letter).collect() }
+    pub fn our_letters  (&self) -> String { self.play.iter().map(|&(letter, _)| letter).collect() }
+
+    pub fn their_shape(&self, letter: char) -> usize { lookup(&self.them, letter) }
+    pub fn shape_to_play(&self, letter: char) -> usize { lookup(&self.play, letter) }
+    pub fn outcome_to_aim_for(&self, letter: char) -> Outcome { lookup(&self.want, letter) }
+}
+
+// the letters are checked against the game when a guide is parsed, so they're all there
+fn lookup<T: Copy>(letters: &[(char, T)], letter: char) -> T {
+    letters.iter()
+           .find(|&&(l, _)| l == letter)
+           .map(|&(_, value)| value)
+           .expect("a letter from the game")
+}
+
+
+/* Parsing */
+
+// the rules are lines of "key = entry, entry, ...". blank lines and lines starting with
+// # are skipped. the shapes come first, as the other rules refer to them by name
+//
+//     shapes   = rock 1, paper 2, scissors 3
+//     beats    = cyclic
+//     outcomes = lose 0, draw 3, win 6
+//     them     = A rock, B paper, C scissors
+//     play     = X rock, Y paper, Z scissors
+//     want     = X lose, Y draw, Z win
+//
+// beats is either a list like "rock > scissors, paper > rock, ..." or cyclic, for an odd
+// number of shapes where each beats the half of the others listed just before it, going
+// round from the first to the last
+impl Game {
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
+        let mut shapes: Option<Vec<Shape>> = None;
+        let mut beats    = None;
+        let mut outcomes = None;
+        let mut them     = None;
+        let mut play     = None;
+        let mut want     = None;
+
+        // the keys set so far, as each can only be set once
+        let mut keys: Vec<&str> = vec![];
+
+        for line in lines(s) {
+            let line = Line { number: line.number, text: line.text.trim_end() };
+            if line.text.is_empty() || line.text.starts_with('#') { continue }
+
+            let mut scanner = line.scanner();
+            let key = scanner.word()?;
+            scanner.skip_spaces();
+            scanner.tag("= ")?;
+            scanner.skip_spaces();
+
+            // everything after the shapes refers to them
+            if key != "shapes" && shapes.is_none() {
+                return Err(line.error(1, "the shapes to be listed first"))
+            }
+            if keys.contains(&key) {
+                return Err(line.error(1, format!("{} to be set only once", key)))
+            }
+            keys.push(key);
+            let named = shapes.as_deref().unwrap_or_default();
+
+            match key {
+                "shapes"   => shapes   = Some(Game::shapes_rule(&mut scanner)?),
+                "beats"    => beats    = Some((line, Game::beats_rule(&mut scanner, named)?)),
+                "outcomes" => outcomes = Some((line, list(&mut scanner, |scanner| Ok((outcome_name(scanner)?, points(scanner)?)))?)),
+                "them"     => them     = Some((line, list(&mut scanner, |scanner| letter_entry(scanner, |scanner| shape_name(scanner, named)))?)),
+                "play"     => play     = Some((line, list(&mut scanner, |scanner| letter_entry(scanner, |scanner| shape_name(scanner, named)))?)),
+                "want"     => want     = Some((line, list(&mut scanner, |scanner| letter_entry(scanner, outcome_name))?)),
+                _          => return Err(line.error(1, "one of shapes, beats, outcomes, them, play or want"))
+            }
+
+            scanner.end()?;
+        }
+
+        let missing = |key: &str| end_of_input(s, format!("a line setting {}", key));
+
+        let shapes                  = shapes.ok_or_else(|| missing("shapes"))?;
+        let (beats_line, beats)     = beats.ok_or_else(|| missing("beats"))?;
+        let (outcomes_line, scores) = outcomes.ok_or_else(|| missing("outcomes"))?;
+        let (them_line, them)       = them.ok_or_else(|| missing("them"))?;
+        let (play_line, play)       = play.ok_or_else(|| missing("play"))?;
+        let (want_line, want)       = want.ok_or_else(|| missing("want"))?;
+
+        // every outcome needs its points, once each
+        let mut outcomes = [None; 3];
+        for (outcome, points) in scores {
+            if outcomes[outcome as usize].replace(points).is_some() {
+                return Err(outcomes_line.error(1, "each outcome only once"))
+            }
+        }
+        let [Some(lose), Some(draw), Some(win)] = outcomes else {
+            return Err(outcomes_line.error(1, "points for lose, draw and win"))
+        };
+
+        // every pair of different shapes has a winner, and every shape beats one and loses
+        // to one, or there'd be no shape to play for some outcome
+        let n = shapes.len();
+        for a in 0..n {
+            let name = &shapes[a].name;
+
+            if beats[a][a] {
+                return Err(beats_line.error(1, format!("{} not to beat itself", name)))
+            }
+            if let Some(b) = (a + 1..n).find(|&b| beats[a][b] == beats[b][a]) {
+                return Err(beats_line.error(1, format!("exactly one of {} and {} to beat the other", name, shapes[b].name)))
+            }
+            if !(0..n).any(|b| beats[a][b]) || !(0..n).any(|b| beats[b][a]) {
+                return Err(beats_line.error(1, format!("{} to beat one shape and lose to another", name)))
+            }
+        }
+
+        for (line, letters) in [(them_line, letters(&them)), (play_line, letters(&play)), (want_line, letters(&want))] {
+            if letters.windows(2).any(|pair| pair[0] == pair[1]) {
+                return Err(line.error(1, "each letter to stand for one thing"))
+            }
+        }
+
+        // our letters mean something different in each part, so both parts need them all
+        if letters(&play) != letters(&want) {
+            return Err(want_line.error(1, "the same letters as play"))
+        }
+
+        Ok(Game { shapes, beats, outcomes: [lose, draw, win], them, play, want })
+    }
+
+    // "rock 1, paper 2, scissors 3", with no name listed twice
+    fn shapes_rule(scanner: &mut Scanner) -> Result<Vec<Shape>, ParseError> {
+        let mut shapes: Vec<Shape> = vec![];
+
+        list(scanner, |scanner| {
+            let column = scanner.column();
+            let shape  = Game::shape_entry(scanner)?;
+
+            if shapes.iter().any(|listed| listed.name == shape.name) {
+                return Err(scanner.error_at(column, "a shape that isn't already listed"))
+            }
+
+            shapes.push(shape);
+            Ok(())
+        })?;
+
+        Ok(shapes)
+    }
+
+    // "rock 1"
+    fn shape_entry(scanner: &mut Scanner) -> Result<Shape, ParseError> {
+        let name = scanner.word()?.to_string();
+        Ok(Shape { name, points: points(scanner)? })
+    }
+
+    fn beats_rule(scanner: &mut Scanner, shapes: &[Shape]) -> Result<Vec<Vec<bool>>, ParseError> {
+        let n = shapes.len();
+        let mut beats = vec![vec![false; n]; n];
+
+        if scanner.rest() == "cyclic" {
+            if n.is_multiple_of(2) { return Err(scanner.error("an odd number of shapes for a cyclic game")) }
+
+            scanner.tag("cyclic")?;
+
+            for a in 0..n {
+                for behind in 1..=n / 2 {
+                    beats[a][(a + n - behind) % n] = true;
+                }
+            }
+
+            return Ok(beats)
+        }
+
+        for (winner, loser) in list(scanner, |scanner| {
+            let winner = shape_name(scanner, shapes)?;
+            scanner.tag(" > ")?;
+            Ok((winner, shape_name(scanner, shapes)?))
+        })? {
+            beats[winner][loser] = true;
+        }
+
+        Ok(beats)
+    }
+}
+
+// entries separated by commas, to the end of the line
+fn list<T>(scanner: &mut Scanner, mut entry: impl FnMut(&mut Scanner) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    let mut entries = vec![entry(scanner)?];
+
+    while !scanner.is_done() {
+        scanner.tag(", ")?;
+        entries.push(entry(scanner)?);
+    }
+
+    Ok(entries)
+}
+
+// " 3", the points after a name
+fn points(scanner: &mut Scanner) -> Result<u32, ParseError> {
+    scanner.tag(" ")?;
+    scanner.number()
+}
+
+// "A rock" or "X win", an upper case letter and what it stands for
+fn letter_entry<T>(scanner: &mut Scanner,
+                   value  : impl FnOnce(&mut Scanner) -> Result<T, ParseError>) -> Result<(char, T), ParseError>
+{
+    let letter = scanner.one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ")?;
+    scanner.tag(" ")?;
+
+    Ok((letter, value(scanner)?))
+}
+
+fn shape_name(scanner: &mut Scanner, shapes: &[Shape]) -> Result<usize, ParseError> {
+    let column = scanner.column();
+    let name   = scanner.word()?;
+
+    shapes.iter()
+          .position(|shape| shape.name == name)
+          .ok_or_else(|| scanner.error_at(column, "the name of one of the shapes"))
+}
+
+fn outcome_name(scanner: &mut Scanner) -> Result<Outcome, ParseError> {
+    let index = scanner.tag_any(&OUTCOMES.map(|(name, _)| name))?;
+    Ok(OUTCOMES[index].1)
+}
+
+// the letters on a line of the rules, sorted
+fn letters<T>(entries: &[(char, T)]) -> Vec<char> {
+    let mut letters: Vec<char> = entries.iter().map(|&(letter, _)| letter).collect();
+    letters.sort_unstable();
+    letters
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = "shapes   = rock 1, paper 2, scissors 3\n\
+                         beats    = cyclic\n\
+                         outcomes = lose 0, draw 3, win 6\n\
+                         them     = A rock, B paper, C scissors\n\
+                         play     = X rock, Y paper, Z scissors\n\
+                         want     = X lose, Y draw, Z win";
+
+    // the rules with one line swapped for another
+    fn with(line: usize, replacement: &str) -> Result<Game, ParseError> {
+        let mut lines: Vec<&str> = RULES.lines().collect();
+        lines[line] = replacement;
+
+        Game::from_string(&lines.join("\n"))
+    }
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let game = Game::rock_paper_scissors();
+
+        assert_eq!(game, Game::from_string(RULES).unwrap());
+        assert_eq!(game.shape_count(), 3);
+        assert_eq!(game.their_letters(), "ABC");
+
+        // paper beats rock, scissors beats paper, rock beats scissors
+        assert_eq!(game.outcome(1, 0), Outcome::Win);
+        assert_eq!(game.outcome(2, 1), Outcome::Win);
+        assert_eq!(game.outcome(0, 2), Outcome::Win);
+        assert_eq!(game.outcome(0, 1), Outcome::Lose);
+        assert_eq!(game.outcome(2, 2), Outcome::Draw);
+
+        assert_eq!(game.respond(0, Outcome::Lose), 2);
+        assert_eq!(game.shape_name(game.respond(2, Outcome::Win)), "rock");
+    }
+
+    #[test]
+    fn test_beats_list() {
+        let game = with(1, "beats    = scissors > paper, paper > rock, rock > scissors").unwrap();
+        assert_eq!(game, Game::rock_paper_scissors());
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let game = Game::from_string(include_str!("../games/rock_paper_scissors_lizard_spock.txt")).unwrap();
+
+        let name = |shape| game.shape_name(shape);
+        let beaten: Vec<Vec<&str>> = (0..5).map(|a| (0..5).filter(|&b| game.outcome(a, b) == Outcome::Win).map(name).collect())
+                                           .collect();
+
+        assert_eq!(beaten, [["lizard", "scissors"], ["rock", "scissors"], ["rock", "spock"], ["spock", "paper"], ["paper", "lizard"]]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let expected = |result: Result<Game, ParseError>| {
+            let error = result.unwrap_err();
+            (error.line, error.column, error.expected)
+        };
+
+        assert_eq!(expected(with(1, "beats    = rock > paper, paper > rock")),
+                   (2, 1, "exactly one of rock and paper to beat the other".to_string()));
+
+        assert_eq!(expected(with(0, "shapes   = rock 1, paper 2, scissors 3, well 4")),
+                   (2, 12, "an odd number of shapes for a cyclic game".to_string()));
+
+        assert_eq!(expected(with(3, "them     = A rock, B paper, C stone")),
+                   (4, 31, "the name of one of the shapes".to_string()));
+
+        assert_eq!(expected(with(2, "outcomes = lose 0, win 6")),
+                   (3, 1, "points for lose, draw and win".to_string()));
+
+        assert_eq!(expected(with(5, "want     = X lose, Y draw, W win")),
+                   (6, 1, "the same letters as play".to_string()));
+
+        assert_eq!(expected(with(4, "play     = X rock, X paper, Z scissors")),
+                   (5, 1, "each letter to stand for one thing".to_string()));
+
+        assert_eq!(expected(with(5, "# no want")),
+                   (6, 10, "a line setting want".to_string()));
+
+        assert_eq!(expected(Game::from_string("beats = cyclic")),
+                   (1, 1, "the shapes to be listed first".to_string()));
+
+        assert_eq!(expected(with(0, "shapes   = rock 1, paper 2, rock 3")),
+                   (1, 29, "a shape that isn't already listed".to_string()));
+    }
+
+    #[test]
+    fn test_repeated_keys() {
+        let expected = |extra: &str| {
+            let error = Game::from_string(&format!("{}\n{}", RULES, extra)).unwrap_err();
+            (error.line, error.column, error.expected)
+        };
+
+        // more shapes after beats would leave it a row short for the new ones
+        assert_eq!(expected("shapes = rock 1, paper 2, scissors 3, well 4"),
+                   (7, 1, "shapes to be set only once".to_string()));
+
+        assert_eq!(expected("want = X win, Y draw, Z lose"),
+                   (7, 1, "want to be set only once".to_string()));
+    }
+}
@@ -0,0 +1,153 @@
+/*  https://adventofcode.com/2022/day/2  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{lines, Line};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+mod decode;
+mod game;
+
+pub use decode::{decode, Decoded, Decoding, Extremes};
+pub use game::{Game, Outcome};
+
+pub struct Input {
+    game  : Game,
+    rounds: Vec<Round>
+}
+
+struct Round {
+    them: usize,
+
+    // in part 1 this is the Shape we play
+    // in part 2 it's the Outcome we want
+    us  : char
+}
+
+// the second letter on a line is the shape we play
+fn part1(input: &Input) -> u32 {
+    input.rounds.iter()
+                .map(|round| points_for_part_1(&input.game, round))
+                .sum()
+}
+
+// the second letter on a line is the outcome we want
+fn part2(input: &Input) -> u32 {
+    input.rounds.iter()
+                .map(|round| points_for_part_2(&input.game, round))
+                .sum()
+}
+
+fn points_for_part_1(game: &Game, round: &Round) -> u32 {
+    game.points(game.shape_to_play(round.us), round.them)
+}
+
+// work backwards from the outcome to the shape that gets it
+fn points_for_part_2(game: &Game, round: &Round) -> u32 {
+    let us = game.respond(round.them, game.outcome_to_aim_for(round.us));
+
+    game.points(us, round.them)
+}
+
+impl Input {
+    // a strategy guide for some other game than rock paper scissors, with its own letters
+    pub fn with_game(s: &str, game: Game) -> Result<Self, ParseError> {
+        let rounds = lines(s).map(|line| Input::line_to_round(line, &game))
+                             .collect::<Result<_, _>>()?;
+
+        Ok(Input { game, rounds })
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    // the total score of following the guide both ways, for part 1 and part 2
+    pub fn totals(&self) -> (u32, u32) {
+        (part1(self), part2(self))
+    }
+
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        Input::with_game(s, Game::rock_paper_scissors())
+    }
+
+    // eg "A Y", both letters are checked here so the lookups above can't fail
+    fn line_to_round(line: Line, game: &Game) -> Result<Round, ParseError> {
+        let mut scanner = line.scanner();
+
+        let them = scanner.one_of(&game.their_letters())?;
+        scanner.tag(" ")?;
+        let us   = scanner.one_of(&game.our_letters())?;
+        scanner.end()?;
+
+        Ok(Round {
+            them: game.their_shape(them),
+            us
+        })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 12);
+    }
+
+    #[test]
+    fn test_rounds() {
+        let game = Game::rock_paper_scissors();
+
+        // "A Y", "B X" and "C Z" from the example, each way
+        let rounds = [Round { them: 0, us: 'Y' }, Round { them: 1, us: 'X' }, Round { them: 2, us: 'Z' }];
+
+        assert_eq!(rounds.iter().map(|round| points_for_part_1(&game, round)).collect::<Vec<_>>(), [8, 1, 6]);
+        assert_eq!(rounds.iter().map(|round| points_for_part_2(&game, round)).collect::<Vec<_>>(), [4, 1, 7]);
+    }
+
+    #[test]
+    fn test_parse_round() {
+        let error = Day02::parse("A Y\nD X").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 1, "one of 'A', 'B', 'C'"));
+    }
+
+    #[test]
+    fn test_other_game() {
+        let game  = Game::from_string(include_str!("../games/rock_paper_scissors_lizard_spock.txt")).unwrap();
+        let input = Input::with_game("A Y\nE V\nC W\nD Z", game).unwrap();
+
+        // playing: rock loses to lizard, rock crushes scissors, spock is disproved by
+        // paper, and scissors decapitates lizard
+        assert_eq!(part1(&input), 4 + 7 + 2 + 11);
+
+        // aiming: of the shapes that get each outcome, the one worth the most points is
+        // played, so paper to beat rock, lizard to lose to scissors, spock to lose to
+        // paper and scissors to beat lizard
+        assert_eq!(part2(&input), 9 + 4 + 2 + 11);
+    }
+
+    fn get_example() -> Input {
+        Day02::parse(common::example!()).unwrap()
+    }
+}
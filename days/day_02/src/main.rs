@@ -1,125 +1,86 @@
 /*  https://adventofcode.com/2022/day/2  */
 
+use common::{Solution, Source};
+use day_02::{Day02, Decoding, Game, Input, Outcome};
+use std::path::Path;
+
+// with --game <rules> the guide is for some other game than rock paper scissors, like the
+// ones under games/. --decode tries every meaning of our letters instead of the ones the
+// rules give them, and prints the best and worst:
+//
+//     $ day_02 --game games/rock_paper_scissors_lizard_spock.txt guide.txt
+//     $ day_02 --decode --example
+//
 fn main() {
-    let input = Input::from("input.txt");
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
-
-enum Shape { Rock, Paper, Scissors }
-enum Outcome { Win, Lose, Draw }
-
-// so we don't have to keep writing Shape:: and Outcome:: in front of the enums
-use crate::Shape::*;
-use crate::Outcome::*;
-
-// points we get for playing a certain shape
-fn shape(shape: Shape) -> u32 {
-    match shape {
-        Rock     => 1,
-        Paper    => 2,
-        Scissors => 3
-    }
-}
-
-// points we get for a certain outcome
-fn outcome(outcome: Outcome) -> u32 {
-    match outcome {
-        Win  => 6,
-        Draw => 3,
-        Lose => 0
-    }
-}
-
-fn letter_to_shape(letter: char) -> Shape {
-    match letter {
-        'A' | 'X' => Rock,
-        'B' | 'Y' => Paper,
-        'C' | 'Z' => Scissors,
-         _        => panic!("Unexpected letter")
-    }
-}
-
-fn letter_to_outcome(letter: char) -> Outcome {
-    match letter {
-        'X' => Lose,
-        'Y' => Draw,
-        'Z' => Win,
-         _  => panic!("Unexpected letter")
+    match args.as_slice() {
+        [flag, rules, rest @ ..] if flag == "--game" => play(rules, rest),
+        [flag, rest @ ..] if flag == "--decode"      => decode(rest),
+        _ => common::main::<Day02>(env!("CARGO_MANIFEST_DIR"))
     }
 }
 
-struct Round {
-    them: Shape,
+fn decode(args: &[String]) {
+    let contents = Source::from_args(args, Path::new(env!("CARGO_MANIFEST_DIR"))).and_then(|source| source.read());
+
+    let input = match contents.map(|contents| Day02::load(&contents)) {
+        Ok(Ok(input)) => input,
+        Ok(Err(error)) => {
+            eprintln!("{}", error);
+            std::process::exit(1)
+        },
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1)
+        }
+    };
 
-    // in part 1 this is the Shape we play
-    // in part 2 it's the Outcome we want
-    us  : char
-}
+    let decoded = day_02::decode(&input);
+    let game    = input.game();
 
-fn points_for_part_1(round: &Round) -> u32 {
-    match (&round.them, letter_to_shape(round.us)) {
-        (Rock, Rock)         => shape(Rock)     + outcome(Draw),
-        (Rock, Paper)        => shape(Paper)    + outcome(Win),
-        (Rock, Scissors)     => shape(Scissors) + outcome(Lose),
-        (Paper, Rock)        => shape(Rock)     + outcome(Lose),
-        (Paper, Paper)       => shape(Paper)    + outcome(Draw),
-        (Paper, Scissors)    => shape(Scissors) + outcome(Win),
-        (Scissors, Rock)     => shape(Rock)     + outcome(Win),
-        (Scissors, Paper)    => shape(Paper)    + outcome(Lose),
-        (Scissors, Scissors) => shape(Scissors) + outcome(Draw),
+    if let Some(shapes) = decoded.shapes {
+        println!("Letters as shapes to play:");
+        print_decoding("best",  &shapes.best,  |&shape| game.shape_name(shape).to_string());
+        print_decoding("worst", &shapes.worst, |&shape| game.shape_name(shape).to_string());
     }
-}
 
-fn points_for_part_2(round: &Round) -> u32 {
-    match (&round.them, letter_to_outcome(round.us)) {
-        (Rock, Win)      => shape(Paper)    + outcome(Win),
-        (Rock, Lose)     => shape(Scissors) + outcome(Lose),
-        (Rock, Draw)     => shape(Rock)     + outcome(Draw),
-        (Paper, Win)     => shape(Scissors) + outcome(Win),
-        (Paper, Lose)    => shape(Rock)     + outcome(Lose),
-        (Paper, Draw)    => shape(Paper)    + outcome(Draw),
-        (Scissors, Win)  => shape(Rock)     + outcome(Win),
-        (Scissors, Lose) => shape(Paper)    + outcome(Lose),
-        (Scissors, Draw) => shape(Scissors) + outcome(Draw),
+    if let Some(outcomes) = decoded.outcomes {
+        println!("Letters as outcomes to aim for:");
+        print_decoding("best",  &outcomes.best,  Outcome::to_string);
+        print_decoding("worst", &outcomes.worst, Outcome::to_string);
     }
 }
 
-struct Input {
-    rounds: Vec<Round>
-}
+// "  best   X=scissors Y=paper Z=rock  24"
+fn print_decoding<T>(label: &str, decoding: &Decoding<T>, name: impl Fn(&T) -> String) {
+    let mapping: Vec<String> = decoding.mapping.iter()
+                                               .map(|(letter, meaning)| format!("{}={}", letter, name(meaning)))
+                                               .collect();
 
-// the second letter on a line is the shape we play
-fn part1(input: &Input) -> u32 {
-    input.rounds.iter()
-                .map(points_for_part_1)
-                .sum()
+    println!("  {:<5}  {}  {}", label, mapping.join(" "), decoding.total);
 }
 
-// the second letter on a line is the outcome we want
-fn part2(input: &Input) -> u32 {
-    input.rounds.iter()
-                .map(points_for_part_2)
-                .sum()
-}
-
-impl Input {
-    fn from(file: &str) -> Self {
-        let contents = std::fs::read_to_string(file).expect("Couldn't read input");
-        let lines = contents.trim().split('\n');
-        
-        Input {
-            rounds: lines.map(Input::line_to_round).collect()
-        }
-    }
-
-    fn line_to_round(line: &str) -> Round {
-        let mut letters = line.split_whitespace();
-    
-        Round {
-            them: letter_to_shape(letters.next().unwrap().chars().next().unwrap()),
-            us  :                 letters.next().unwrap().chars().next().unwrap()
+fn play(rules: &str, args: &[String]) {
+    let game = std::fs::read_to_string(rules).map_err(|error| format!("error: couldn't read {}: {}", rules, error))
+                                             .and_then(|rules| Game::from_string(&rules).map_err(|error| error.to_string()));
+
+    let input = game.and_then(|game| {
+        let contents = Source::from_args(args, Path::new(env!("CARGO_MANIFEST_DIR"))).and_then(|source| source.read())
+                                                                                      .map_err(|error| format!("error: {}", error))?;
+
+        Input::with_game(contents.trim_end(), game).map_err(|error| error.to_string())
+    });
+
+    match input {
+        Ok(input) => {
+            let (part1, part2) = input.totals();
+            println!("Part 1: {}", part1);
+            println!("Part 2: {}", part2);
+        },
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1)
         }
     }
 }
@@ -0,0 +1,144 @@
+use std::ops::{BitAnd, BitOr};
+
+// a set of rucksack items as the bits of one number. bit 1 is 'a' up to bit 26 for 'z',
+// then 'A' to 'Z' are bits 27 to 52, so each item's bit is its priority. intersecting or
+// joining sets is then a single and/or, however many items they hold
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    // every item there is
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    // the items in a rucksack, or the index of the first character that isn't an item
+    pub fn from_items(items: &str) -> Result<Self, usize> {
+        items.chars()
+             .enumerate()
+             .try_fold(ItemSet::EMPTY, |set, (index, item)| Ok(set | ItemSet::single(item).ok_or(index)?))
+    }
+
+    // a set of just this item, if it is one
+    pub fn single(item: char) -> Option<Self> {
+        priority(item).map(|priority| ItemSet(1 << priority))
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    // the items in order of priority
+    pub fn items(&self) -> impl Iterator<Item = char> + '_ {
+        (1..=52).filter(|priority| self.0 & 1 << priority != 0)
+                .map(item)
+    }
+
+    pub fn priorities(&self) -> u32 {
+        (1..=52).filter(|priority| self.0 & 1 << priority != 0)
+                .sum()
+    }
+
+    // the items in every one of the sets. with no sets at all that's every item
+    pub fn intersection(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+        sets.into_iter().fold(ItemSet::ALL, |common, set| common & set)
+    }
+
+    // the items in any of the sets
+    pub fn union(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+        sets.into_iter().fold(ItemSet::EMPTY, |all, set| all | set)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+}
+
+// each item has a priority as specified in the problem description. anything that isn't
+// a letter isn't an item, so has none
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 1 + 26),
+         _        => None
+    }
+}
+
+// the item with a priority from 1 to 52
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26  => (b'a' + (priority - 1) as u8) as char,
+        27..=52 => (b'A' + (priority - 27) as u8) as char,
+        _       => panic!("priority out of range")
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(priority('a'), Some(1));
+        assert_eq!(priority('z'), Some(26));
+        assert_eq!(priority('A'), Some(27));
+        assert_eq!(priority('Z'), Some(52));
+        assert_eq!(priority('1'), None);
+        assert_eq!(priority('é'), None);
+
+        assert!((1..=52).all(|p| priority(item(p)) == Some(p)));
+    }
+
+    #[test]
+    fn test_from_items() {
+        let set = ItemSet::from_items("abZab").unwrap();
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains('Z') && !set.contains('c') && !set.contains('!'));
+        assert_eq!(set.items().collect::<String>(), "abZ");
+        assert_eq!(set.priorities(), 1 + 2 + 52);
+
+        assert_eq!(ItemSet::from_items("ab-c"), Err(2));
+        assert_eq!(ItemSet::from_items(""), Ok(ItemSet::EMPTY));
+
+        assert_eq!(ItemSet::single('b'), Some(ItemSet(1 << 2)));
+        assert_eq!(ItemSet::single('-'), None);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let sets = ["vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", "PmmdzqPrVvPwwTWBwg"]
+                       .map(|items| ItemSet::from_items(items).unwrap());
+
+        assert_eq!(ItemSet::intersection(sets).items().collect::<String>(), "r");
+        assert_eq!((sets[0] & sets[1]).items().collect::<String>(), "frsFM");
+        assert_eq!((sets[0] | sets[2]), ItemSet::union(sets.into_iter().filter(|&set| set != sets[1])));
+
+        assert_eq!(ItemSet::intersection([]), ItemSet::ALL);
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert!(ItemSet::union([]).is_empty());
+    }
+}
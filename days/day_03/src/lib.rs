@@ -0,0 +1,164 @@
+/*  https://adventofcode.com/2022/day/3  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{lines, Line};
+use std::num::NonZeroUsize;
+
+mod item_set;
+
+pub use item_set::{priority, ItemSet};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { rucksacks : Vec<Rucksack> }
+
+// the elves in each group of part 2
+const GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+// a rucksack's two compartments
+struct Rucksack {
+    left : ItemSet,
+    right: ItemSet
+}
+
+impl Rucksack {
+    // items are letters, anything else would have no priority
+    fn from(line: Line) -> Result<Self, ParseError> {
+        let middle = line.text.len() / 2;
+        let mut halves = [ItemSet::EMPTY; 2];
+
+        // every character before the one being looked at was a letter, a byte each, so
+        // counting characters counts bytes too until the first one that isn't an item
+        for (index, item) in line.text.chars().enumerate() {
+            let item = ItemSet::single(item).ok_or_else(|| line.error(index + 1, "an item from a-z or A-Z"))?;
+            let half = usize::from(index >= middle);
+
+            halves[half] = halves[half] | item;
+        }
+
+        let [left, right] = halves;
+        Ok(Rucksack { left, right })
+    }
+
+    fn items(&self) -> ItemSet {
+        self.left | self.right
+    }
+
+    // the items in both halves of this rucksack
+    fn common_items(&self) -> ItemSet {
+        self.left & self.right
+    }
+
+    // the items common to every rucksack in a group
+    fn common_items_group(group: &[Rucksack]) -> ItemSet {
+        ItemSet::intersection(group.iter().map(Rucksack::items))
+    }
+}
+
+impl Input {
+    // the sum of priorities of the items common to each group of this many rucksacks. the
+    // last group is whatever's left over, if they don't divide evenly
+    pub fn badges(&self, group_size: NonZeroUsize) -> u32 {
+        self.rucksacks.chunks(group_size.get())
+                      .map(|group| Rucksack::common_items_group(group).priorities())
+                      .sum()
+    }
+}
+
+// return the sum of priorities of the common types within each rucksack
+fn part1(input: &Input) -> u32 {
+    input.rucksacks.iter()
+                   .map(|rucksack| rucksack.common_items().priorities())
+                   .sum()
+}
+
+// return the sum of priorities of types common to groups of 3 rucksacks
+fn part2(input: &Input) -> u32 {
+    input.badges(GROUP_SIZE)
+}
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let rucksacks = lines(s).map(Rucksack::from)
+                                .collect::<Result<_, _>>()?;
+
+        Ok(Input { rucksacks })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 157);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 70);
+    }
+
+    #[test]
+    fn test_rucksack_left_right() {
+        let rucksack = Rucksack::from(Line::new("abcdef")).unwrap();
+        assert_eq!(rucksack.left , ItemSet::from_items("abc").unwrap());
+        assert_eq!(rucksack.right, ItemSet::from_items("def").unwrap());
+    }
+
+    #[test]
+    fn test_rucksack_common_items() {
+        let rucksack = Rucksack::from(Line::new("abcb")).unwrap();
+        assert_eq!(rucksack.common_items().items().collect::<String>(), "b");
+
+        // every common item counts, not just the first
+        let rucksack = Rucksack::from(Line::new("abZxZba")).unwrap();
+        assert_eq!(rucksack.common_items().items().collect::<String>(), "abZ");
+    }
+
+    #[test]
+    fn test_rucksack_bad_item() {
+        let error = Rucksack::from(Line::new("abc1ef")).err().unwrap();
+        assert_eq!(error.column, 4);
+
+        let error = Rucksack::from(Line::new("abcde!")).err().unwrap();
+        assert_eq!(error.column, 6);
+
+        let error = Rucksack::from(Line::new("abcdéf")).err().unwrap();
+        assert_eq!(error.column, 5);
+
+        let error = Rucksack::from(Line::new("éa")).err().unwrap();
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_group_sizes() {
+        let input = get_example();
+
+        let badges = |group_size| input.badges(NonZeroUsize::new(group_size).unwrap());
+
+        // each rucksack on its own, pairs, and all six together, which have nothing in common
+        assert_eq!(badges(1), 2278);
+        assert_eq!(badges(2), 371);
+        assert_eq!(badges(6), 0);
+    }
+
+    fn get_example() -> Input {
+        Day03::parse(common::example!()).unwrap()
+    }
+}
@@ -0,0 +1,138 @@
+/*  https://adventofcode.com/2022/day/4  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{lines, Line, Scanner};
+use intervals::IntervalSet;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { pairs : Vec<Pair> }
+
+struct Pair {
+    elf1: SectionRange,
+    elf2: SectionRange
+}
+
+struct SectionRange {
+    from: usize,
+    to  : usize
+}
+
+impl SectionRange {
+    fn sections(&self) -> IntervalSet<usize> {
+        IntervalSet::from(self.from..=self.to)
+    }
+
+    // nothing of the other's is left once ours are taken away
+    fn fully_contains(&self, other: &SectionRange) -> bool {
+        other.sections().difference(&self.sections()).is_empty()
+    }
+
+    fn overlaps_with(&self, other: &SectionRange) -> bool {
+        !self.sections().intersection(&other.sections()).is_empty()
+    }
+}
+
+// count number of pairs where one section range fully contains the other
+fn part1(input: &Input) -> usize {
+    input.pairs.iter()
+               .filter(|pair|    pair.elf1.fully_contains(&pair.elf2)
+                              || pair.elf2.fully_contains(&pair.elf1))
+               .count()
+}
+
+// count number of pairs that overlap
+fn part2(input: &Input) -> usize {
+    input.pairs.iter()
+               .filter(|pair| pair.elf1.overlaps_with(&pair.elf2))
+               .count()
+}
+
+
+/* Parsing */
+
+// 2-4,6-8
+impl Pair {
+    fn from(line: Line) -> Result<Self, ParseError> {
+        let mut scanner = line.scanner();
+
+        let elf1 = SectionRange::from(&mut scanner)?;
+        scanner.tag(",")?;
+        let elf2 = SectionRange::from(&mut scanner)?;
+        scanner.end()?;
+
+        Ok(Pair { elf1, elf2 })
+    }
+}
+
+impl SectionRange {
+    fn from(scanner: &mut Scanner) -> Result<Self, ParseError> {
+        let from = scanner.number()?;
+        scanner.tag("-")?;
+
+        let column = scanner.column();
+        let to = scanner.number()?;
+
+        if to < from {
+            return Err(scanner.error_at(column, format!("a section from {} on", from)))
+        }
+
+        Ok(SectionRange { from, to })
+    }
+}
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let pairs = lines(s).map(Pair::from)
+                            .collect::<Result<_, _>>()?;
+
+        Ok(Input { pairs })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(s: &str) -> SectionRange {
+        SectionRange::from(&mut Line::new(s).scanner()).unwrap()
+    }
+
+    #[test]
+    fn test_fully_contains() {
+        assert!(range("2-8").fully_contains(&range("3-7")));
+        assert!(range("4-6").fully_contains(&range("6-6")));
+        assert!(! range("2-4").fully_contains(&range("6-8")));
+        assert!(! range("5-7").fully_contains(&range("7-9")));
+    }
+
+    #[test]
+    fn test_overlaps_with() {
+        assert!(range("5-7").overlaps_with(&range("7-9")));
+        assert!(range("2-8").overlaps_with(&range("3-7")));
+        assert!(range("6-6").overlaps_with(&range("4-6")));
+        assert!(range("2-6").overlaps_with(&range("4-8")));
+        assert!(! range("2-4").overlaps_with(&range("6-8")));
+        assert!(! range("2-3").overlaps_with(&range("4-5")));
+    }
+
+    #[test]
+    fn test_bad_range() {
+        let error = Pair::from(Line::new("2-4,8-6")).err().unwrap();
+        assert_eq!(error.column, 7);
+    }
+}
@@ -0,0 +1,240 @@
+/*  https://adventofcode.com/2022/day/5  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{end_of_input, lines, Line, Scanner};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input {
+    ship : Ship,
+    moves: Vec<Move>
+}
+
+type Ship  = Vec<Vec<Crate>>;
+type Crate = char;
+
+struct Move {
+    amount: usize,
+    from  : usize,
+    to    : usize
+}
+
+// move 1 crate at a time
+fn part1(input: &Input) -> String {
+
+    // clone the ship from the input since we'll need to mutate it when moving crates around
+    let mut ship = input.ship.clone();
+
+    for m in &input.moves {
+        for _ in 0 .. m.amount {
+            let letter = ship[m.from].pop().unwrap();
+            ship[m.to].push(letter);
+        }
+    }
+
+    top_crates(&ship)
+}
+
+// a move of more than 1 crate takes all of them at once (preserves their order)
+fn part2(input: &Input) -> String {
+    let mut ship = input.ship.clone();
+
+    for m in &input.moves {
+        let     at   = ship[m.from].len() - m.amount;
+        let mut grab = ship[m.from].split_off(at);     // rust's built-in Vec::split_off()
+
+        ship[m.to].append(&mut grab);
+    }
+
+    top_crates(&ship)
+}
+
+// build a string from the top crate from every stack that has a crate
+fn top_crates(ship: &Ship) -> String {   
+    ship.iter()
+        .skip(1)
+        .filter_map(|stack| stack.iter().last())
+        .collect()
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = lines(s).collect();
+
+        // the input is in two sections, the ship graphic and the move list
+        let Some(blank) = lines.iter().position(|line| line.text.is_empty()) else {
+            return Err(end_of_input(s, "a blank line between the crates and the moves"))
+        };
+
+        let ship  = Input::parse_ship(&lines[..blank])?;
+        let moves = Input::parse_moves(&lines[blank+1 ..], &ship)?;
+
+        Ok(Input { ship, moves })
+    }
+
+    //       [D]    
+    //   [N] [C]    
+    //   [Z] [M] [P]
+    //    1   2   3 
+    fn parse_ship(levels: &[Line]) -> Result<Ship, ParseError> {
+
+        // the last line holds the stack numbers, which tells us how many stacks there are.
+        // with a blank line first there's no ship at all
+        let Some((numbers, levels)) = levels.split_last() else {
+            return Err(end_of_input("", "a line of stack numbers"))
+        };
+        let stack_count = numbers.text.split_whitespace().count();
+
+        // allocate space, adding 1 so we can use 1-based indices throughout the code
+        let mut ship : Vec<Vec<Crate>> = vec![vec![]; stack_count + 1];
+
+        // work bottom up
+        for level in levels.iter().rev() {
+
+            // rust has real Unicode strings so we can't easily get a letter at a certain
+            // index, but the input is normal ASCII so we can treat it as a slice of bytes
+            let chars = level.text.as_bytes();
+
+            // go through this level of crates, skipping blank chars (no crate). lines
+            // should be space-padded to the end but an editor might have trimmed them
+            for (i, stack) in ship.iter_mut().enumerate().skip(1) {
+
+                // pick out the crate letter in between the square brackets
+                let column = (i-1)*4 + 1;
+                let letter = chars.get(column).copied().unwrap_or(b' ');
+                if letter == b' ' { continue }
+
+                if !letter.is_ascii_uppercase() {
+                    return Err(level.error(column + 1, "a crate letter from A-Z"))
+                }
+
+                stack.push(letter as char);
+            }
+        }
+
+        Ok(ship)
+    }
+
+    // "move 1 from 2 to 3"
+    // "move 4 from 5 to 6"
+    //
+    // both parts move the same number of crates between the same stacks, so the stacks'
+    // heights go the same way in each. following them here means the solvers never have
+    // to take more crates off a stack than it holds
+    fn parse_moves(moves: &[Line], ship: &Ship) -> Result<Vec<Move>, ParseError> {
+        let mut heights: Vec<usize> = ship.iter().map(Vec::len).collect();
+
+        moves.iter()
+             .map(|&line| {
+                 let m = Move::from(line, ship.len() - 1)?;
+
+                 if m.amount > heights[m.from] {
+                     return Err(line.error(6, format!("at most the {} crates on stack {}", heights[m.from], m.from)))
+                 }
+
+                 heights[m.from] -= m.amount;
+                 heights[m.to]   += m.amount;
+
+                 Ok(m)
+             })
+             .collect()
+    }
+}
+
+impl Move {    
+    fn from(line: Line, stack_count: usize) -> Result<Self, ParseError> {
+        let mut scanner = line.scanner();
+
+        // "move 1 from 2 to 3"
+        scanner.tag("move ")?;
+        let amount = scanner.number()?;
+        scanner.tag(" from ")?;
+
+        let stack = |scanner: &mut Scanner| {
+            let column = scanner.column();
+
+            match scanner.number()? {
+                n if (1..=stack_count).contains(&n) => Ok(n),
+                _ => Err(scanner.error_at(column, format!("a stack from 1 to {}", stack_count)))
+            }
+        };
+
+        let from = stack(&mut scanner)?;
+        scanner.tag(" to ")?;
+        let to   = stack(&mut scanner)?;
+        scanner.end()?;
+
+        Ok(Move { amount, from, to })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ship() {
+        let example =  "    [D]    \n\
+                        [N] [C]    \n\
+                        [Z] [M] [P]\n\
+                         1   2   3 ";
+
+        let ship = Input::parse_ship(&lines(example).collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(ship.len(), 1+3);
+        assert_eq!(ship[1], vec!['Z','N']);
+        assert_eq!(ship[2], vec!['M','C','D']);
+        assert_eq!(ship[3], vec!['P']);
+    }
+
+    #[test]
+    fn test_parse_move() {
+        let m = Move::from(Line::new("move 1 from 2 to 3"), 3).unwrap();
+        assert_eq!(m.amount, 1);
+        assert_eq!(m.from, 2);
+        assert_eq!(m.to, 3);
+
+        let error = Move::from(Line::new("move 1 from 4 to 3"), 3).err().unwrap();
+        assert_eq!(error.column, 13);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Input::from_string("\n[A]\n 1 \n\nmove 1 from 1 to 1").err().unwrap();
+        assert_eq!(error.expected, "a line of stack numbers");
+
+        // the second move takes more crates off stack 1 than are left there
+        let error = Input::from_string("[A]    \n[B] [C]\n 1   2 \n\nmove 1 from 2 to 1\nmove 4 from 1 to 2").err().unwrap();
+        assert_eq!((error.line, error.column), (6, 6));
+        assert_eq!(error.expected, "at most the 3 crates on stack 1");
+    }
+}
+
+/*  $ cargo run
+    Compiling day_05 v0.1.0 (C:\Users\Jason\Documents\GitHub\aoc-2022\day_05)
+        Finished dev [unoptimized + debuginfo] target(s) in 0.44s
+        Running `target\debug\day_05.exe`
+    Part 1: SHMSDGZVC
+    Part 2: VRZGHDFBQ
+
+    $ cargo run
+    Part 1: 7777-33-66-3-0-66-88-3-33-7777
+    Part 2: 7777-33-66-3-0-66-88-3-33-7777
+*/
@@ -0,0 +1,71 @@
+/*  https://adventofcode.com/2022/day/6  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::Line;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { buffer: Vec<u8> }
+
+// find the first index where the prior n chars are all distinct
+fn part1(input: &Input) -> usize { marker_with_window_size(&input.buffer, 4)  }
+fn part2(input: &Input) -> usize { marker_with_window_size(&input.buffer, 14) }
+
+fn marker_with_window_size(buffer: &[u8], size: usize) -> usize {
+    use std::collections::HashSet;
+
+    // the index is of the char just after the window
+    for (index, window) in (size..).zip(buffer.windows(size)) {
+        // load the bytes from this window into a set, it will de-duplicate for us
+        let set: HashSet<&u8> = HashSet::from_iter(window);
+
+        // if the size of our set is the size of the window, all chars in it were distinct
+        if set.len() == size {
+            return index
+        }
+    }
+
+    0
+}
+
+impl Input {
+    // the datastream is a single line of lowercase letters
+    fn from_string(string: &str) -> Result<Self, ParseError> {
+        let line = Line::new(string);
+
+        if let Some(index) = string.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(line.error(index + 1, "a letter from a-z"))
+        }
+
+        Ok(Input {
+            buffer: string.as_bytes()
+                          .to_vec()
+        })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marker_with_window_size() {
+        let input = Input::from_string("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        assert_eq!(marker_with_window_size(&input.buffer, 4) , 7);
+        assert_eq!(marker_with_window_size(&input.buffer, 14), 19);
+    }
+}
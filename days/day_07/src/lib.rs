@@ -0,0 +1,242 @@
+/*  https://adventofcode.com/2022/day/7  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{lines, Line};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { system: Directory }
+
+struct Directory {
+    name : String,
+    dirs : Vec<Directory>,
+    files: Vec<File>,
+    total_size: usize
+}
+
+type File = usize;
+
+// sum the sizes of all directories up to 100k in size
+fn part1(input: &Input) -> usize {
+    let totals = get_all_totals_from(&input.system);
+
+    totals.into_iter()
+          .filter(|&size| size <= 100000)
+          .sum()
+}
+
+// find the smallest directory we'd have to delete to free enough space
+fn part2(input: &Input) -> usize {
+
+    let free_at_least = 30_000_000 - (70_000_000 - input.system.total_size);
+
+    let mut totals = get_all_totals_from(&input.system);
+
+    totals.sort();
+    totals.into_iter()
+          .find(|&size| size >= free_at_least)
+          .expect("assumed at least one size >= free_at_least")
+}
+
+fn get_all_totals_from(system: &Directory) -> Vec<usize> {
+    let mut sizes = vec![];
+    get_total_sizes(system, &mut sizes);
+    sizes
+}
+
+fn get_total_sizes(system: &Directory, vec: &mut Vec<usize>) {
+    system.dirs.iter()
+               .for_each(|dir| get_total_sizes(dir, vec));
+
+    vec.push(system.total_size)
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+
+        // the top node in our tree. everything will be added somewhere under root
+        let mut root = Directory::new("".to_string());
+
+        // push and pop off the stack of directory names as we encounter cd commands
+        let mut path: Vec<String> = vec![];
+
+        let mut lines = lines(s);
+
+        // the session always starts off at the root
+        match lines.next() {
+            Some(line) if line.text == "$ cd /" => {},
+            Some(line) => return Err(line.error(1, "\"$ cd /\"")),
+            None       => return Err(Line::new("").error(1, "\"$ cd /\""))
+        }
+
+        for line in lines {
+
+            // changing directory
+            if let Some(dir) = line.text.strip_prefix("$ cd ") {
+                match dir {
+                    ".." => {
+                        if path.pop().is_none() {
+                            return Err(line.error(6, "a directory name (already at the root)"))
+                        }
+                    },
+                    dir  => {
+                        path.push(dir.to_string());
+
+                        // only directories we've seen listed can be entered
+                        if at_path_do(&mut root, &path, |_| ()).is_none() {
+                            return Err(line.error(6, "a directory listed by ls"))
+                        }
+                    }
+                }
+            }
+
+            // nothing to do for ls
+            else if line.text == "$ ls" {
+            }
+
+            // a directory
+            else if let Some(dir) = line.text.strip_prefix("dir ") {
+                at_path_do(&mut root,
+                           &path,
+                           |node| node.dirs.push(Directory::new(dir.to_string())));
+            }
+
+            // a file with a size/name (the name isn't used anywhere so we don't collect it)
+            else {
+                let size = file_size_from(line)?;
+
+                at_path_do(&mut root,
+                           &path,
+                           |node| node.files.push(size));
+            }
+        }
+
+        // not really a parsing task, it's more a task for the domain to handle, but
+        // since the system is mutable for the moment let's just add the sizes in now
+        tally_sizes(&mut root);
+
+        Ok(Input {
+            system: root
+        })
+    }
+}
+
+// recursively sum the size of directories based on their files and sub-dirs sizes
+fn tally_sizes(node: &mut Directory) -> usize {
+
+    node.total_size =
+        node.dirs.iter_mut()        // this needs to be iter_mut() because within map's call to
+                 .map(tally_sizes)  // tally_sizes the node.total_size field will be updated
+                 .sum::<usize>()
+
+      + node.files.iter()           // add the total file sizes from this directory
+                  .sum::<usize>();
+
+    node.total_size
+}
+
+// recursively descend through the filesystem to a certain path,
+// then do some operation on that directory. None if the path doesn't exist
+fn at_path_do<F>(node: &mut Directory,
+                 path: &[String],
+                 operation: F) -> Option<()>
+    where F: Fn(&mut Directory)
+{
+    // we're at the target path
+    if path.is_empty() {
+        // this will either add a file or a directory depending on how
+        // it was defined in the calling code
+        operation(node);
+        Some(())
+    } else {
+        // recurse into the next directory in the path
+        at_path_do(node.dirs.iter_mut()
+                            .find(|dir| dir.name == path[0])?,
+                   &path[1..],
+                   operation)
+    }
+}
+
+impl Directory {
+    fn new(name: String) -> Self {
+        Directory {
+            name,
+            files: vec![],
+            dirs: vec![],
+            total_size: 0
+        }
+    }
+}
+
+// "14848514 b.txt", anything that isn't a command or a dir has to be a file
+fn file_size_from(line: Line) -> Result<usize, ParseError> {
+    let mut scanner = line.scanner();
+
+    if scanner.peek() == Some('$') {
+        return Err(scanner.error("a command of \"$ cd\" or \"$ ls\""))
+    }
+
+    let size = scanner.number()?;
+    scanner.tag(" ")?;
+
+    Ok(size)
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tree() {
+        let input = get_example();
+        assert_eq!(input.system.files.len(), 2);
+        assert_eq!(input.system.dirs.len(), 2);
+    }
+
+    #[test]
+    fn test_tally_sizes() {
+        let input = get_example();
+        assert_eq!(input.system.total_size, 48381165);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Input::from_string("$ cd /\n$ cd ..").err().unwrap().line, 2);
+        assert_eq!(Input::from_string("$ cd /\n$ cd a" ).err().unwrap().line, 2);
+        assert_eq!(Input::from_string("$ cd /\n$ dir" ).err().unwrap().line, 2);
+        assert_eq!(Input::from_string("$ cd /\nabc d" ).err().unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = get_example();
+        assert_eq!(part1(&input), 95437);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = get_example();
+        assert_eq!(part2(&input), 24933642);
+    }
+
+    fn get_example() -> Input {
+        Day07::parse(common::example!()).unwrap()
+    }
+}
@@ -0,0 +1,186 @@
+/*  https://adventofcode.com/2022/day/8  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{end_of_input, lines, Line};
+use grid::Grid;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { trees : Grid<u32> }
+
+// how many trees are visible from outside of the forest
+fn part1(input: &Input) -> usize {
+    let trees = &input.trees;
+    let size  = trees.width();
+
+    // a grid of bools with the same dimensions as the input, all false to start
+    let mut visible: Grid<bool> = trees.map(|_| false);
+
+    // left-to-right fly-overs
+    for row in 0..size {
+        let visibles = fly_over(trees.row(row).iter().copied());
+        for (col, is_visible) in visibles.iter().enumerate() {
+            visible[(row, col)] |= is_visible;
+        }
+    }
+
+    // right-to-left
+    for row in 0..size {
+        let mut visibles = fly_over(trees.row(row).iter().rev().copied());
+        visibles.reverse();
+        for (col, is_visible) in visibles.iter().enumerate() {
+            visible[(row, col)] |= is_visible;
+        }
+    }
+
+    // top-down
+    for col in 0..size {
+        let visibles = fly_over(trees.column(col).copied());
+        for row in 0..size {
+            visible[(row, col)] |= visibles[row];
+        }
+    }
+
+    // bottom-up
+    for col in 0..size {
+        let mut visibles = fly_over(trees.column(col).rev().copied());
+        visibles.reverse();
+        for row in 0..size {
+            visible[(row, col)] |= visibles[row];
+        }
+    }
+
+    visible.iter()
+           .filter(|(_, is_visible)| **is_visible)
+           .count()
+}
+
+// see which trees are visible by flying over a line of them
+fn fly_over(mut trees: impl Iterator<Item=u32>) -> Vec<bool> {
+
+    // the first tree in a line is always visible
+    let mut visible = vec![true];
+    let mut highest = trees.next().expect("expected at least one tree in a line");
+
+    // this for loop starts with the second height because the trees iterator already
+    // passed over the first height when we called next() on it above
+    for tree in trees {
+        if tree > highest {
+            visible.push(true);
+            highest = tree;
+        } else {
+            visible.push(false);
+        }
+    }
+
+    visible
+}
+
+// find the tree that sees the most other trees from its vantage point
+fn part2(input: &Input) -> usize {
+    let trees = &input.trees;
+
+    trees.iter()
+         .map(|(pos, &height)| {
+             let to_right = count_visible(trees.ray(pos, ( 0,  1)).copied(), height);
+             let to_left  = count_visible(trees.ray(pos, ( 0, -1)).copied(), height);
+             let to_down  = count_visible(trees.ray(pos, ( 1,  0)).copied(), height);
+             let to_up    = count_visible(trees.ray(pos, (-1,  0)).copied(), height);
+
+             to_right * to_left * to_down * to_up
+         })
+         .max().unwrap()
+}
+
+// count the number of visible trees until our view is obstructed
+fn count_visible(mut heights: impl Iterator<Item=u32>, from: u32) -> usize {
+    let mut count = 0;
+
+    loop {
+        let height = heights.next();
+
+        match height {
+            Some(height) => {
+                if height < from {
+                    count += 1;
+                } else {
+                    return count + 1
+                }
+            },
+
+            // we've run out of trees, the count must be all of them
+            None => return count
+        }
+    }
+}
+
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = lines(s.trim_end()).collect();
+
+        if lines.is_empty() {
+            return Err(end_of_input(s, "a row of trees"))
+        }
+
+        // the forest is square, the parts walk it with a single size
+        for line in &lines {
+            if line.text.len() != lines.len() {
+                let column = line.text.len().min(lines.len()) + 1;
+                return Err(line.error(column, format!("{} trees in each row", lines.len())))
+            }
+        }
+
+        Ok(Input {
+            trees: Grid::from_lines(&lines, |c| c.to_digit(10), "a tree height from 0-9")?
+        })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = get_example();
+        assert_eq!(part1(&input), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = get_example();
+        assert_eq!(part2(&input), 8);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Input::from_string("12\n3x").err().unwrap().column, 2);
+        assert_eq!(Input::from_string("123\n45").err().unwrap().line, 1);
+        assert_eq!(Input::from_string("\n").err().unwrap().expected, "a row of trees");
+    }
+
+    #[test]
+    fn test_fly_over() {
+        assert_eq!(fly_over([3,0,3,7,3].iter().copied()),
+                   vec![true,false,false,true,false]);
+    }
+
+    fn get_example() -> Input {
+        Day08::parse(common::example!()).unwrap()
+    }
+}
@@ -0,0 +1,211 @@
+/*  https://adventofcode.com/2022/day/9  */
+
+use common::{Answer, Palette, ParseError, Render, Solution, Visualizer};
+use common::parse::{lines, Line};
+use geometry::{Cardinal, Point};
+use std::collections::HashSet;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+
+    // the long rope from part 2, after each move
+    fn visualize(input: &Input, visualizer: &mut Visualizer) -> bool {
+        pull_rope_length(&input.moves, 10, visualizer);
+        true
+    }
+}
+
+pub struct Input { moves: Vec<Move> }
+
+struct Move {
+    direction: Cardinal,
+    steps: usize
+}
+
+fn part1(input: &Input) -> usize { pull_rope_length(&input.moves,  2, &mut Visualizer::off()) }
+fn part2(input: &Input) -> usize { pull_rope_length(&input.moves, 10, &mut Visualizer::off()) }
+
+// count the number of coordinates the tail of the rope visits as it's pulled around a grid
+fn pull_rope_length(moves: &[Move], length: usize, visualizer: &mut Visualizer) -> usize {
+
+    // start the whole rope bunched up on the 0,0 coordinate
+    let mut rope: Vec<Point> = vec![Point::ORIGIN; length];
+
+    // save the location of the tail after every move
+    let mut visited = HashSet::new();
+    visited.insert(Point::ORIGIN);
+
+    for (i, Move {direction, steps}) in moves.iter().enumerate() {
+        for _ in 1..=*steps {
+
+            // move the first knot by one step and catch the rest up
+            rope[0] += direction.vector();
+            deslackify(&mut rope);
+
+            // remember the location of the tail after this step
+            visited.insert(rope[length-1]);
+        }
+
+        visualizer.show(format_args!("move {} of {}", i + 1, moves.len()), &Rope { knots: &rope, visited: &visited });
+    }
+
+    visited.len()
+}
+
+// move knots of the rope as needed to remove slack. a knot that's fallen more than one
+// step behind the one ahead of it moves one step towards it, diagonally if need be
+fn deslackify(rope: &mut [Point]) {
+
+    for i in 0..rope.len()-1 {
+        let leader   = rope[i  ];
+        let follower = rope[i+1];
+
+        if leader.chebyshev(follower) > 1 {
+            rope[i+1] += (leader - follower).signum();
+        }
+    }
+}
+
+
+/* Visualization */
+
+// the rope partway through its moves, and everywhere its tail has been
+struct Rope<'a> {
+    knots  : &'a [Point],
+    visited: &'a HashSet<Point>
+}
+
+impl Render for Rope<'_> {
+    // drawn like the puzzle: the head is H, the tail T (or its number on a long rope), s is
+    // where it started and # is everywhere the tail's been. the frame grows to fit them all
+    fn render(&self) -> String {
+        let everything = || self.knots.iter().chain(self.visited.iter());
+
+        let left   = everything().map(|knot| knot.x).min().unwrap();
+        let right  = everything().map(|knot| knot.x).max().unwrap();
+        let top    = everything().map(|knot| knot.y).min().unwrap();
+        let bottom = everything().map(|knot| knot.y).max().unwrap();
+
+        let mut text = String::new();
+
+        for y in top..=bottom {
+            for x in left..=right {
+                let here = Point::new(x, y);
+
+                // the knot nearest the head wins when they're bunched up
+                text.push(match self.knots.iter().position(|&knot| knot == here) {
+                    Some(0)                              => 'H',
+                    Some(_) if self.knots.len() == 2     => 'T',
+                    Some(i)                              => char::from_digit(i as u32, 36).unwrap(),
+                    None if here == Point::ORIGIN        => 's',
+                    None if self.visited.contains(&here) => '#',
+                    None                                 => '.'
+                });
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+
+    // the knots in red, fading to orange at the tail
+    fn palette(&self) -> Palette {
+        Palette::new([16, 16, 32], &[
+            ('#', [64, 64, 96]), ('s', [255, 255, 255]), ('H', [255, 32, 32]), ('T', [255, 160, 32]),
+            ('1', [255, 64, 32]), ('2', [255, 80, 32]), ('3', [255, 96, 32]), ('4', [255, 112, 32]),
+            ('5', [255, 128, 32]), ('6', [255, 136, 32]), ('7', [255, 144, 32]), ('8', [255, 152, 32]),
+            ('9', [255, 160, 32])
+        ])
+    }
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Input, ParseError> {
+        let moves = lines(s.trim_end()).map(Move::from_line)
+                                       .collect::<Result<_, _>>()?;
+
+        Ok(Input { moves })
+    }
+}
+
+impl Move {
+    // "R 4"
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let mut scanner = line.scanner();
+
+        let direction = match scanner.one_of("RLUD")? {
+            'R' => Cardinal::East,
+            'L' => Cardinal::West,
+            'U' => Cardinal::North,
+             _  => Cardinal::South
+        };
+        scanner.tag(" ")?;
+        let steps = scanner.number()?;
+        scanner.end()?;
+
+        Ok(Move { direction, steps })
+    }
+}
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = get_example1();
+        assert_eq!(part1(&input), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example1()), 1);
+        assert_eq!(part2(&get_example2()), 36);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        pull_rope_length(&get_example1().moves[..2], 2, &mut visualizer);
+
+        let frames = visualizer.recorded();
+        assert_eq!(frames[0].text, "s##TH\n");
+        assert_eq!(frames[1].title, "move 2 of 2");
+        assert_eq!(frames[1].text, "....H\n\
+                                    ....T\n\
+                                    ....#\n\
+                                    ....#\n\
+                                    s###.\n");
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        pull_rope_length(&get_example1().moves, 2, &mut visualizer);
+
+        common::assert_snapshot!("snapshots/example.gif", &visualizer.gif());
+    }
+
+    fn get_example1() -> Input {
+        Day09::parse(common::example!()).unwrap()
+    }
+
+    fn get_example2() -> Input {
+        Day09::parse(common::example!("example2.txt")).unwrap()
+    }
+}
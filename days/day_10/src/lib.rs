@@ -0,0 +1,186 @@
+/*  https://adventofcode.com/2022/day/10  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{end_of_input, lines, Line};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { instructions: Vec<Instruction> }
+
+enum Instruction {
+    NoOp,
+    AddX(i32)
+}
+
+use Instruction::*;
+
+// calculate the value of the register at each cycle
+fn part1(input: &Input) -> i32 {
+    let mut register = 1;
+    let mut cycles: Vec<i32> = vec![register];
+
+    for instruction in input.instructions.iter() {
+        match instruction {
+
+            NoOp => {
+                cycles.push(register);
+            },
+
+            AddX(x) => {
+                cycles.push(register);
+                cycles.push(register);
+
+                // update the register only at the end of the cycle
+                register += x;
+            }
+        }
+    }
+
+    let get_cycles: Vec<i32> = vec![20, 60, 100, 140, 180, 220];
+
+    get_cycles.into_iter()
+              .map(|cycle| cycle * cycles[cycle as usize])
+              .sum()
+}
+
+// draw an ASCII diagram on a CRT
+fn part2(input: &Input) -> String {
+    let mut register: i32 = 1;
+    let mut cycle: i32 = 1;
+    let mut crt: String = String::new();
+
+    for instruction in input.instructions.iter() {
+        match instruction {
+
+            NoOp => {
+                output_to_crt(&mut crt, register, cycle); cycle += 1;
+            },
+
+            AddX(x) => {
+                output_to_crt(&mut crt, register, cycle); cycle += 1;
+                output_to_crt(&mut crt, register, cycle); cycle += 1;
+
+                // update the register only at the end of the cycle
+                register += x;
+            }
+        }
+    }
+
+    crt
+}
+
+fn output_to_crt(crt: &mut String, register: i32, cycle: i32) {
+
+    // wrap to 40 columns
+    let cycle = (cycle-1) % 40;
+
+    if [cycle-1, cycle, cycle+1].contains(&register) {
+        crt.push('#');
+    } else {
+        crt.push('.');
+    }
+
+    if cycle+1 == 40 { crt.push('\n'); }
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let instructions: Vec<Instruction> = lines(s).map(Instruction::from_line)
+                                                     .collect::<Result<_, _>>()?;
+
+        // part 1 reads the register as late as the 220th cycle
+        let cycles: usize = instructions.iter().map(Instruction::cycles).sum();
+        if cycles < 220 {
+            return Err(end_of_input(s, format!("instructions for 220 cycles, not {}", cycles)))
+        }
+
+        Ok(Input { instructions })
+    }
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            NoOp    => 1,
+            AddX(_) => 2
+        }
+    }
+
+    // "noop" or "addx -5"
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let mut scanner = line.scanner();
+
+        let instruction = match scanner.tag_any(&["noop", "addx "])? {
+            0 => NoOp,
+            _ => AddX(scanner.number()?)
+        };
+
+        scanner.end()?;
+        Ok(instruction)
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 13140);
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        let error = Instruction::from_line(Line::new("addx five")).err().unwrap();
+        assert_eq!(error.column, 6);
+
+        let error = Input::from_string("noop\naddx 3").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.expected, "instructions for 220 cycles, not 3");
+    }
+
+    #[test]
+    fn test_part2() {
+        let expected = "##..##..##..##..##..##..##..##..##..##..\n\
+                        ###...###...###...###...###...###...###.\n\
+                        ####....####....####....####....####....\n\
+                        #####.....#####.....#####.....#####.....\n\
+                        ######......######......######......####\n\
+                        #######.......#######.......#######.....\n".to_string();
+
+        assert_eq!(part2(&get_example()), expected);
+    }
+
+    fn get_example() -> Input {
+        Day10::parse(common::example!()).unwrap()
+    }
+}
+
+/*  $ cargo run
+
+    Part 1: 17840
+    Part 2:
+    ####..##..#.....##..#..#.#....###...##..
+    #....#..#.#....#..#.#..#.#....#..#.#..#.
+    ###..#..#.#....#....#..#.#....#..#.#....
+    #....####.#....#.##.#..#.#....###..#.##.
+    #....#..#.#....#..#.#..#.#....#....#..#.
+    ####.#..#.####..###..##..####.#.....###.
+*/
@@ -0,0 +1,289 @@
+/*  https://adventofcode.com/2022/day/11  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{blocks, Line, Scanner};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { monkeys: Vec<Monkey> }
+
+struct Monkey {
+    items        : Vec<u64>,
+    operation    : Operation,
+    divisible_by : u64,
+    if_true      : usize,
+    if_false     : usize
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Operation {
+    Multiply(u64),
+    Add(u64),
+    Square
+}
+
+// 20 rounds and the custom operation to keep from overflowing is to divide by 3
+fn part1(input: &Input) -> usize {    
+    monkey_in_the_middle(&input.monkeys,
+                         20,
+                         |worry| worry / 3)
+}
+
+// 10,000 rounds and the custom operation is to mod by the product of the divisors
+fn part2(input: &Input) -> usize {
+    
+    let product_of_divisors =
+        input.monkeys.iter()
+                     .map(|m| m.divisible_by as u32)
+                     .product::<u32>() as u64;
+
+    monkey_in_the_middle(&input.monkeys,
+                         10_000,
+                         |worry| worry % product_of_divisors)
+}
+
+fn monkey_in_the_middle<F>(monkeys: &[Monkey],
+                           rounds: usize,
+                           custom_op: F) -> usize
+    where F: Fn(u64) -> u64
+{
+    // track the number of inspections by each monkey
+    let mut inspections: Vec<usize> = vec![0; monkeys.len()];
+
+    // clone the items queues because we'll be mutating them in place
+    let mut items: Vec<Vec<u64>> = monkeys.iter()
+                                          .map(|monkey| monkey.items.clone())
+                                          .collect();
+
+    for _ in 0..rounds {
+        for (m, monkey) in monkeys.iter().enumerate() {
+
+            // drain this monkey's items into their own vector so we can iterate over them,
+            // otherwise rust complains about two references to the items vec at the same time
+            let worries: Vec<u64> = items[m].drain(..).collect();
+
+            // do an operation on each worry level in the monkey's list
+            for worry in worries {
+                let worry = operate(&monkey.operation, worry);
+                let worry = custom_op(worry);
+
+                let catcher = if worry.is_multiple_of(monkey.divisible_by) {
+                                  monkey.if_true
+                              } else {
+                                  monkey.if_false
+                              };
+
+                items[catcher].push(worry);
+
+                // tally an inspection for this monkey
+                inspections[m] += 1;
+            }
+        }
+    }
+
+    // find the two most active monkeys
+    inspections.sort();
+    inspections.into_iter()
+               .rev()
+               .take(2)
+               .product()
+}
+
+fn operate(operation: &Operation, worry: u64) -> u64 {
+    match operation {
+        Multiply(x) => worry * x,
+        Add(x)      => worry + x,
+        Square      => worry * worry
+    }
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let blocks = blocks(s);
+
+        let monkeys: Vec<Monkey> = blocks.iter()
+                                         .map(|block| Monkey::from_lines(block))
+                                         .collect::<Result<_, _>>()?;
+
+        // monkeys can only throw to monkeys that exist
+        for (monkey, block) in monkeys.iter().zip(&blocks) {
+            for (target, line) in [(monkey.if_true, block[4]), (monkey.if_false, block[5])] {
+                if target >= monkeys.len() {
+                    let column = line.text.rfind(' ').unwrap() + 2;
+                    return Err(line.error(column, format!("a monkey from 0 to {}", monkeys.len() - 1)))
+                }
+            }
+        }
+
+        Ok(Input { monkeys })
+    }
+}
+
+impl Monkey {
+    fn from_lines(lines: &[Line]) -> Result<Self, ParseError> {
+
+        // start scanning a line of the block after its label, eg "  Test: divisible by "
+        let scan = |index: usize, label: &str| -> Result<Scanner, ParseError> {
+            let Some(line) = lines.get(index) else {
+                let last = lines.last().unwrap();
+                return Err(last.error(last.text.len() + 1, format!("another line with {:?}", label)))
+            };
+
+            let mut scanner = line.scanner();
+            scanner.skip_spaces();
+            scanner.tag(label)?;
+
+            Ok(scanner)
+        };
+
+        let mut scanner = scan(0, "Monkey ")?;
+        scanner.number::<usize>()?;
+        scanner.tag(":")?;
+        scanner.end()?;
+
+        let mut scanner = scan(1, "Starting items: ")?;
+        let mut items = vec![scanner.number()?];
+        while !scanner.is_done() {
+            scanner.tag(", ")?;
+            items.push(scanner.number()?);
+        }
+
+        let mut scanner = scan(2, "Operation: new = ")?;
+        let operation = Operation::from_expression(&mut scanner)?;
+        scanner.end()?;
+
+        let number = |index, label| -> Result<u64, ParseError> {
+            let mut scanner = scan(index, label)?;
+            let number = scanner.number()?;
+            scanner.end()?;
+
+            Ok(number)
+        };
+
+        Ok(Monkey {
+            items,
+            operation,
+            divisible_by: number(3, "Test: divisible by "       )?,
+            if_true     : number(4, "If true: throw to monkey " )? as usize,
+            if_false    : number(5, "If false: throw to monkey ")? as usize
+        })
+    }
+}
+
+impl Operation {
+    fn from_expression(scanner: &mut Scanner) -> Result<Self, ParseError> {
+        // the left half of the expression is always "old"
+        scanner.tag("old ")?;
+
+        let symbol = scanner.one_of("+*")?;
+        scanner.tag(" ")?;
+
+        let operation = match (symbol, scanner.rest()) {
+            ('*', "old") => { scanner.tag("old")?; Square },
+            ('*', _    ) => Multiply(scanner.number()?),
+             _           => Add(scanner.number()?)
+        };
+
+        Ok(operation)
+    }
+}
+
+use Operation::*;
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse::lines;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 10605);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_monkey() {
+        let monkey = "Monkey 0:\n\
+                      Starting items: 79, 98\n\
+                      Operation: new = old * 19\n\
+                      Test: divisible by 23\n\
+                        If true: throw to monkey 2\n\
+                        If false: throw to monkey 3";
+
+        let monkey = Monkey::from_lines(&lines(monkey).collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(monkey.items, vec![79, 98]);
+        assert_eq!(monkey.operation, Multiply(19));
+        assert_eq!(monkey.divisible_by, 23);
+        assert_eq!(monkey.if_true, 2);
+        assert_eq!(monkey.if_false, 3);
+    }
+
+    #[test]
+    fn test_operation_from_expression() {
+        let expression = |s| Operation::from_expression(&mut Line::new(s).scanner());
+
+        assert_eq!(expression("old * 12" ), Ok(Multiply(12)));
+        assert_eq!(expression("old + 12" ), Ok(Add(12)));
+        assert_eq!(expression("old * old"), Ok(Square));
+        assert_eq!(expression("old - 12" ).err().unwrap().column, 5);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Input::from_string("Monkey 0:\n  Starting items: 1, x").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 22));
+
+        let error = Input::from_string("Monkey 0:\n  Starting items: 1").err().unwrap();
+        assert_eq!((error.line, error.expected.as_str()), (2, "another line with \"Operation: new = \""));
+    }
+
+    fn get_example() -> Input {
+        Day11::parse(common::example!()).unwrap()
+    }
+}
+
+/*  $ grep Test input.txt | sort
+    Test: divisible by 11
+    Test: divisible by 13
+    Test: divisible by 17
+    Test: divisible by 19
+    Test: divisible by 2
+    Test: divisible by 3
+    Test: divisible by 5
+    Test: divisible by 7
+
+    λ> product [2,3,5,7,11,13,17,19]
+    9699690
+
+    λ> logBase 2 (product [2,3,5,7,11,13,17,19])
+    23.209507209138437
+
+    λ> logBase 2 (square $ product [2,3,5,7,11,13,17,19])
+    46.41901441827687
+
+    $ cargo run   
+    Part 1: 50830
+    Part 2: 14399640002
+ */
@@ -0,0 +1,146 @@
+/*  https://adventofcode.com/2022/day/12  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::end_of_input;
+use geometry::Point;
+use grid::Grid;
+use search::Paths;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input {
+    heightmap: HeightMap,
+    start    : Point,
+    end      : Point
+}
+
+type HeightMap = Grid<char>;
+
+// find the shortest path from start to end (backwards using the opposite stepping logic)
+fn part1(input: &Input) -> usize {
+    let paths = search_from_end(input);
+    paths.distance(&input.start).unwrap()
+}
+
+// find the shortest path to any 'a' starting at the end again
+fn part2(input: &Input) -> usize {
+    let paths = search_from_end(input);
+
+    positions_of('a', &input.heightmap)
+        .into_iter()
+        .filter_map(|pos| paths.distance(&pos))
+        .min().unwrap()
+}
+
+// every step is the same distance, so a breadth-first search from the end finds the
+// shortest path to every position that can reach it
+fn search_from_end(input: &Input) -> Paths<Point, usize> {
+    let heightmap = &input.heightmap;
+
+    search::bfs(input.end, |&u: &Point| {
+        u.neighbours4()
+         .filter(|&v| heightmap.at(v).is_some())
+         .filter(move |&v| can_step_to(v, u, heightmap))
+    })
+}
+
+// can we make a step on our grid. since we do our searches backwards, the logic is opposite
+// to the problem description, ie, can we make this step, is it no more than 1 lower
+fn can_step_to(to:   Point,
+               from: Point,
+               heightmap: &HeightMap) -> bool
+{
+      heightmap[from] as i32
+    - heightmap[to] as i32
+   <= 1
+}
+
+// get all positions of a certain elevation in a heightmap
+fn positions_of(elevation: char, heightmap: &HeightMap) -> Vec<Point> {
+    heightmap.points()
+             .filter(|&pos| heightmap[pos] == elevation)
+             .collect()
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let elevation = |c| match c {
+            'a'..='z' | 'S' | 'E' => Some(c),
+            _                     => None
+        };
+
+        let mut heightmap = Grid::parse(s, elevation, "an elevation from a-z, 'S' or 'E'")?;
+
+        let find = |marker| heightmap.points().find(|&pos| heightmap[pos] == marker);
+
+        let start = find('S').ok_or_else(|| end_of_input(s, "a start position 'S' somewhere"))?;
+        let end   = find('E').ok_or_else(|| end_of_input(s, "a best signal position 'E' somewhere"))?;
+
+        // the start and end are at the lowest and highest elevations
+        heightmap[start] = 'a';
+        heightmap[end]   = 'z';
+
+        Ok(Input { heightmap, start, end })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 31);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 29);
+    }
+
+    #[test]
+    fn test_parse() {
+        let map = get_example();
+        assert_eq!(map.start, Point::new(0, 0));
+        assert_eq!(map.end,   Point::new(5, 2));
+        assert_eq!(map.heightmap[(0, 0)], 'a');
+        assert_eq!(map.heightmap[(2, 5)], 'z');
+        assert_eq!(map.heightmap[(1, 1)], 'b');
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Input::from_string("Sab\nc!E").err().unwrap().column, 2);
+        assert_eq!(Input::from_string("Sab\ncd" ).err().unwrap().line, 2);
+        assert_eq!(Input::from_string("Sab\ncde").err().unwrap().expected,
+                   "a best signal position 'E' somewhere");
+    }
+
+    fn get_example() -> Input {
+        Day12::parse(common::example!()).unwrap()
+    }
+}
+
+/*  $ time target/release/day_12.exe
+    Part 1: 534
+    Part 2: 525
+
+    real    0m0.170s
+*/
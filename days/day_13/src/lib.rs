@@ -0,0 +1,214 @@
+/*  https://adventofcode.com/2022/day/13  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{blocks, Line};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { pairs: Vec<(Packet, Packet)> }
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Packet {
+    Number(u8),
+    List(Vec<Packet>)
+}
+
+// sum the indices of the pairs that are in the right order
+fn part1(input: &Input) -> usize {
+    input.pairs.iter()
+               .map(|(left, right)| in_right_order(left, right))
+               .enumerate()
+               .map(|(i, ordering)| if ordering == Ordering::Less {i+1} else {0})
+               .sum()
+}
+
+// find the indices of the two new packets in the ordered list of all the packets
+fn part2(input: &Input) -> usize {
+
+    // combine all the pairs into a list
+    let mut pairs: Vec<Packet> =
+        input.pairs.iter()
+                   .flat_map(|(left, right)| vec![left.clone(), right.clone()])
+                   .collect();
+
+    // add the two new packets defined in the problem description
+    let new1 = Packet::from_string("[[2]]");
+    let new2 = Packet::from_string("[[6]]");
+
+    pairs.push(new1.clone());
+    pairs.push(new2.clone());
+
+    pairs.sort_by(in_right_order);
+
+    let index1 = pairs.iter().position(|val| *val == new1).unwrap() + 1;
+    let index2 = pairs.iter().position(|val| *val == new2).unwrap() + 1;
+
+    index1 * index2
+}
+
+fn in_right_order(left : &Packet,
+                  right: &Packet) -> Ordering
+{
+    match (left, right) {
+        (Number(l), Number(r)) => l.cmp(r),
+
+        // if we're comparing a number to a list
+        (Number(num), right) => in_right_order(&Packet::list_from_number(*num), right),
+        (left, Number(num) ) => in_right_order(left, &Packet::list_from_number(*num)),
+
+        (List(left), List(right)) => {
+            if left.is_empty() && right.is_empty() { return Ordering::Equal   }
+            if left.is_empty()                     { return Ordering::Less    }
+            if right.is_empty()                    { return Ordering::Greater }
+
+            match in_right_order(&left[0], &right[0]) {
+                Ordering::Equal => {
+                    let l: Vec<Packet> = left. iter().skip(1).cloned().collect();
+                    let r: Vec<Packet> = right.iter().skip(1).cloned().collect();
+
+                    in_right_order(&List(l), &List(r))
+                }
+
+                ordering => ordering
+            }
+        }
+    }
+}
+
+/* Parsing  */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let pairs = blocks(s).iter()
+                             .map(|block| match block[..] {
+                                 [left, right] => Ok((Packet::from_line(left)?,
+                                                      Packet::from_line(right)?)),
+                                 _ => {
+                                     let line = block.get(2).unwrap_or(&block[0]);
+                                     Err(line.error(1, "pairs of packets separated by blank lines"))
+                                 }
+                             })
+                             .collect::<Result<_, _>>()?;
+
+        Ok(Input { pairs })
+    }
+}
+
+impl Packet {
+    // 10
+    fn parse_number(s: &str) -> IResult<&str, Self> {
+        map_res(digit1, |num: &str| { num.parse().map(Number) })
+           (s)
+    }
+
+    // [1,2,[3,4],[],5]
+    fn parse_list(s: &str) -> IResult<&str, Self> {
+        // cut() so an error deep inside a list is reported there rather than
+        // backtracking out to the start of the line
+        let parser =
+            preceded(
+                tag("["),
+                cut(terminated(
+                    separated_list0(tag(","), alt((Packet::parse_number,
+                                                   Packet::parse_list))),
+                    tag("]")
+                ))
+            );
+
+        map(parser, |list| { List(list) })
+           (s)
+    }
+
+    fn list_from_number(num: u8) -> Packet {
+        List(vec![Number(num)])
+    }
+
+    // for the divider packets we make ourselves
+    fn from_string(s: &str) -> Self {
+        Packet::parse_list(s).unwrap().1
+    }
+
+    // a packet from the input, which has to be a list taking up the whole line
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let remaining = match Packet::parse_list(line.text) {
+            Ok(("", packet)) => return Ok(packet),
+            Ok((remaining, _)) => remaining,
+            Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error.input,
+            Err(nom::Err::Incomplete(_)) => ""
+        };
+
+        let column = line.text.len() - remaining.len() + 1;
+        Err(line.error(column, "a packet of '[', ']', ',' and numbers up to 255"))
+    }
+}
+
+/* Imports */
+
+use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::combinator::{cut, map, map_res};
+use nom::multi::separated_list0;
+use nom::sequence::{preceded, terminated};
+use std::cmp::Ordering;
+use Packet::*;
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 140);
+    }
+
+    #[test]
+    fn parse_number() {
+        let x = Packet::parse_number("23u");
+        assert_eq!(x.unwrap(), ("u", Packet::Number(23)));
+    }
+
+    #[test]
+    fn parse_list() {
+        let list = Packet::parse_list("[[1,2],3,4,[]]").unwrap();
+        let expected = List(vec![List(vec![Number(1),
+                                           Number(2)]),
+                                 Number(3),
+                                 Number(4),
+                                 List(vec![])]);
+
+        assert_eq!(list, ("", expected));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Packet::from_line(Line::new("[1,[2,x]]")).err().unwrap();
+        assert_eq!(error.column, 6);
+
+        let error = Input::from_string("[1]\n[2]\n[3]").err().unwrap();
+        assert_eq!(error.line, 3);
+    }
+
+    fn get_example() -> Input {
+        Day13::parse(common::example!()).unwrap()
+    }
+}
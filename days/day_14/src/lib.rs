@@ -0,0 +1,281 @@
+/*  https://adventofcode.com/2022/day/14  */
+
+use common::{Answer, Palette, ParseError, Render, Solution, Visualizer};
+use common::parse::{end_of_input, lines, Line, Scanner};
+use geometry::Point;
+use grid::Grid;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+
+    // the sand piling up in part 1, one grain at a time
+    fn visualize(input: &Input, visualizer: &mut Visualizer) -> bool {
+        falling_sand(input, 1, visualizer);
+        true
+    }
+}
+
+pub struct Input { paths : Vec<Path>  }
+struct Path  { points: Vec<Point> }
+
+// count how many grains of sand come to rest before they start sliding off forever
+fn part1(input: &Input) -> usize {
+    falling_sand(input, 1, &mut Visualizer::off())
+}
+
+// count how many come to rest when we include the floor we added beneath the rock paths
+fn part2(input: &Input) -> usize {
+    falling_sand(input, 2, &mut Visualizer::off())
+}
+
+// where the sand pours in from
+const SOURCE: Point = Point::new(500, 0);
+
+// the ways a grain of sand tries to fall, in order: straight down, down and to the left,
+// then down and to the right
+const FALLS: [Point; 3] = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+fn falling_sand(input: &Input, part: usize, visualizer: &mut Visualizer) -> usize {
+    let mut cave = build_cave(&input.paths);
+
+    let lowest = input.paths.iter()
+                            .flat_map(|path| path.points.iter().map(|point| point.y))
+                            .max().unwrap();
+
+    let mut sand = SOURCE;
+    let mut rest = 0;
+
+    loop {
+        if part == 1 && sand.y >= lowest { break }
+
+        // fall to the first place there's air
+        if let Some(fall) = FALLS.iter().find(|&&fall| cave[sand + fall] == Type::Air) {
+            sand += *fall;
+            continue
+        }
+
+        // nowhere for this sand to fall so it settles here
+        cave[sand] = Type::Sand;
+        rest += 1;
+
+        visualizer.show(format_args!("{} grains at rest", rest), &Scan { cave: &cave, floor: lowest + 2 });
+
+        // part 2 ends when the grain of sand couldn't fall at all
+        if part == 2 && sand == SOURCE { break }
+
+        // start a new grain of sand
+        sand = SOURCE;
+    }
+
+    rest
+}
+
+#[derive(Clone, Eq, PartialEq)]
+enum Type {
+    Air,
+    Rock,
+    Sand
+}
+
+type Cave = Grid<Type>;
+
+fn build_cave(paths: &[Path]) -> Cave {
+    let rightest = paths.iter()
+                        .flat_map(|path| path.points.iter().map(|point| point.x))
+                        .max().unwrap();
+
+    let lowest   = paths.iter()
+                        .flat_map(|path| path.points.iter().map(|point| point.y))
+                        .max().unwrap();
+
+    // for part 2 we need an "infinite" floor along the bottom, or for our purposes
+    // enough floor to support a triangle of falling sand
+    let rightest = rightest + lowest;
+
+    let mut cave: Cave = Grid::new(rightest as usize + 2, lowest as usize + 3, Type::Air);
+
+    // fill in the rocks along the paths, a step at a time from one point to the next
+    for path in paths {
+        for pair in path.points.windows(2) {
+            let step = (pair[1] - pair[0]).signum();
+            let mut rock = pair[0];
+
+            cave[rock] = Type::Rock;
+
+            while rock != pair[1] {
+                rock += step;
+                cave[rock] = Type::Rock;
+            }
+        }
+    }
+
+    // add the floor for part 2
+    for x in 0..=rightest+1 {
+        cave[Point::new(x, lowest+2)] = Type::Rock;
+    }
+
+    cave
+}
+
+
+/* Visualization */
+
+// the cave as it is between grains of sand. the floor under it all is left out
+struct Scan<'a> {
+    cave : &'a Cave,
+    floor: i64
+}
+
+impl Render for Scan<'_> {
+    // drawn like the puzzle: # for rock, o for sand and + where the sand pours in, cropped
+    // to everything that's not air
+    fn render(&self) -> String {
+        let filled: Vec<Point> = self.cave.points()
+                                          .filter(|&point| point.y < self.floor && self.cave[point] != Type::Air)
+                                          .chain([SOURCE])
+                                          .collect();
+
+        let left   = filled.iter().map(|point| point.x).min().unwrap();
+        let right  = filled.iter().map(|point| point.x).max().unwrap();
+        let bottom = filled.iter().map(|point| point.y).max().unwrap();
+
+        let mut text = String::new();
+
+        for y in 0..=bottom {
+            for x in left..=right {
+                let here = Point::new(x, y);
+
+                text.push(match self.cave[here] {
+                    Type::Rock                  => '#',
+                    Type::Sand                  => 'o',
+                    Type::Air if here == SOURCE => '+',
+                    Type::Air                   => '.'
+                });
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+
+    fn palette(&self) -> Palette {
+        Palette::new([24, 16, 16], &[('#', [112, 104, 96]), ('o', [240, 200, 96]), ('+', [255, 255, 255])])
+    }
+}
+
+
+/* Parsing  */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let paths: Vec<Path> = lines(s).map(Path::from_line)
+                                       .collect::<Result<_, _>>()?;
+
+        if paths.is_empty() {
+            return Err(end_of_input(s, "a path of rock"))
+        }
+
+        Ok(Input { paths })
+    }
+}
+
+impl Path {
+    // 498,4 -> 498,6 -> 496,6
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let mut scanner = line.scanner();
+        let mut points  = vec![point_from(&mut scanner)?];
+
+        while !scanner.is_done() {
+            scanner.tag(" -> ")?;
+            points.push(point_from(&mut scanner)?);
+        }
+
+        Ok(Path { points })
+    }
+}
+
+// 498,4. the cave has no negative coordinates
+fn point_from(scanner: &mut Scanner) -> Result<Point, ParseError> {
+    let x: usize = scanner.number()?;
+    scanner.tag(",")?;
+    let y: usize = scanner.number()?;
+
+    Ok(Point::new(x as i64, y as i64))
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 24);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 93);
+    }
+
+    #[test]
+    fn test_parse_path() {
+        let error = Path::from_line(Line::new("498,4 -> 498,6 - 496,6")).err().unwrap();
+        assert_eq!(error.column, 15);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        Day14::visualize(&get_example(), &mut visualizer);
+
+        let frames = visualizer.recorded();
+        assert_eq!(frames.len(), 24);
+
+        assert_eq!(frames[4].text, "......+...\n\
+                                    ..........\n\
+                                    ..........\n\
+                                    ..........\n\
+                                    ....#...##\n\
+                                    ....#...#.\n\
+                                    ..###...#.\n\
+                                    ......o.#.\n\
+                                    ....oooo#.\n\
+                                    #########.\n");
+
+        assert_eq!(frames[23].text, "......+...\n\
+                                     ..........\n\
+                                     ......o...\n\
+                                     .....ooo..\n\
+                                     ....#ooo##\n\
+                                     ...o#ooo#.\n\
+                                     ..###ooo#.\n\
+                                     ....oooo#.\n\
+                                     .o.ooooo#.\n\
+                                     #########.\n");
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        Day14::visualize(&get_example(), &mut visualizer);
+
+        common::assert_snapshot!("snapshots/example.gif", &visualizer.gif());
+    }
+
+    fn get_example() -> Input {
+        Day14::parse(common::example!()).unwrap()
+    }
+}
@@ -0,0 +1,280 @@
+/*  https://adventofcode.com/2022/day/15  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{end_of_input, lines, Line};
+use geometry::Point;
+use intervals::IntervalSet;
+use std::collections::HashSet;
+use std::ops::Range;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input, input.scale().0).into() }
+    fn part2(input: &Input) -> Answer { part2(input, input.scale().1).into() }
+}
+
+pub struct Input { sensors : Vec<Sensor> }
+
+struct Sensor {
+    pos   : Point,
+    beacon: Point
+}
+
+impl Input {
+    // the row for part 1 and the search area for part 2. the puzzle text's example uses
+    // much smaller ones than the real input, which is easy to tell from its coordinates
+    fn scale(&self) -> (i64, usize) {
+        let small = self.sensors.iter().all(|sensor| sensor.pos.x.abs() < 100 && sensor.pos.y.abs() < 100);
+
+        if small { (10, 20) } else { (2_000_000, 4_000_000) }
+    }
+}
+
+// how many positions on a given row are covered by the sensor field
+fn part1(input: &Input, row: i64) -> usize {
+    row_coverage(&input.sensors, row)
+}
+
+fn row_coverage(sensors: &[Sensor], row: i64) -> usize {
+    // merge the sensors' ranges on this row, keeping them all sorted and non-overlapping
+    let covered: IntervalSet<i64> = sensors.iter()
+                                           .filter_map(|sensor| get_x_range_on_y_for_sensor(row, sensor))
+                                           .collect();
+
+    // the beacons on the row are inside the sensors' fields but can't be ruled out
+    let beacons: HashSet<Point> = sensors.iter()
+                                         .map(|sensor| sensor.beacon)
+                                         .filter(|beacon| beacon.y == row)
+                                         .collect();
+
+    covered.len() as usize - beacons.len()
+}
+
+// get the range of columns that this sensor's field intersects with on row y
+fn get_x_range_on_y_for_sensor(y: i64, sensor: &Sensor) -> Option<Interval> {
+    let manhattan = sensor.pos.manhattan(sensor.beacon);
+
+    let row_dy = (sensor.pos.y - y).abs();
+
+    let min_x = sensor.pos.x - (manhattan - row_dy);
+    let max_x = sensor.pos.x + (manhattan - row_dy);
+
+    if min_x <= max_x {
+        Some(Interval::new(min_x, max_x))
+    } else {
+        None
+    }
+}
+
+// find the only unaccounted-for coordinate in a square grid after rendering all sensor fields
+fn part2(input: &Input, size: usize) -> usize {
+    let pos = get_unaccounted_position(&input.sensors, size);
+
+      pos.x as usize * 4_000_000
+    + pos.y as usize
+}
+
+fn get_unaccounted_position(sensors: &[Sensor], size: usize) -> Point {
+    let range = Interval::new(0, size as i64);
+
+    // the rows are split into bands that are worked on at the same time. the first band
+    // with a gap in it has the lowest row with one, the same as looking in order
+    parallel::chunks(0..size + 1, |rows| gap_in_rows(sensors, rows, &range))
+        .into_iter()
+        .flatten()
+        .next()
+        .unwrap_or(Point::ORIGIN)
+}
+
+// render the sensor fields over just these rows and look for a gap in them
+fn gap_in_rows(sensors: &[Sensor], rows: Range<usize>, range: &Interval) -> Option<Point> {
+    let first = rows.start as i64;
+    let band  = Interval::new(first, rows.end as i64 - 1);
+
+    let mut covered: Vec<IntervalSet<i64>> = vec![IntervalSet::new(); rows.len()];
+
+    for sensor in sensors {
+        let sensor_rows = row_range(sensor);
+
+        for row in *sensor_rows.start().max(band.start())..=*sensor_rows.end().min(band.end()) {
+            if let Some(range) = get_x_range_on_y_for_sensor(row, sensor) {
+                covered[(row - first) as usize].insert(range);
+            }
+        }
+    }
+
+    for (row_idx, row) in covered.into_iter().enumerate() {
+        let gaps = row.complement(range.clone());
+
+        // there should only be one row with a gap in it
+        if let [gap] = gaps.intervals() {
+            return Some(Point::new(*gap.start(), first + row_idx as i64))
+        }
+    }
+
+    None
+}
+
+// get the range of rows spanned by this sensor and its beacon
+fn row_range(sensor: &Sensor) -> Interval {
+    let manhattan = sensor.pos.manhattan(sensor.beacon);
+
+    Interval::new(sensor.pos.y - manhattan,
+                  sensor.pos.y + manhattan)
+}
+
+
+/* Intervals */
+
+type Interval = std::ops::RangeInclusive<i64>;
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let sensors: Vec<Sensor> = lines(s).map(Sensor::from_line)
+                                           .collect::<Result<_, _>>()?;
+
+        if sensors.is_empty() {
+            return Err(end_of_input(s, "a sensor"))
+        }
+
+        Ok(Input { sensors })
+    }
+}
+
+impl Sensor {
+    // "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let mut scanner = line.scanner();
+
+        scanner.tag("Sensor at x=")?;
+        let x = scanner.number()?;
+        scanner.tag(", y=")?;
+        let y = scanner.number()?;
+        let pos = Point::new(x, y);
+
+        scanner.tag(": closest beacon is at x=")?;
+        let x = scanner.number()?;
+        scanner.tag(", y=")?;
+        let y = scanner.number()?;
+        let beacon = Point::new(x, y);
+
+        scanner.end()?;
+
+        Ok(Sensor { pos, beacon })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(get_example().scale(), (10, 20));
+    }
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(part1(&get_example(), 10), 26);
+
+        // no beacon on this row, so every covered position counts
+        assert_eq!(part1(&get_example(), 20), 27);
+    }
+
+    #[test]
+    fn test_part2_example() {
+       assert_eq!(part2(&get_example(), 20), 56000011);
+    }
+
+    #[test]
+    #[ignore = "needs the puzzle input in input.txt"]
+    fn test_part1_mine() {
+        assert_eq!(part1(&get_input(), 2_000_000), 4883971);
+    }
+
+    #[test]
+    #[ignore = "needs the puzzle input in input.txt"]
+    fn test_part2_mine() {
+       assert_eq!(part2(&get_input(), 4_000_000), 12691026767556);
+    }
+
+    #[test]
+    fn test_row_range() {
+        let sensor = Sensor {
+            pos   : Point::new(8,  7),
+            beacon: Point::new(2, 10)
+        };
+
+        let range = row_range(&sensor);
+
+        assert_eq!(*range.start(), -2);
+        assert_eq!(*range.end()  , 16);
+    }
+
+    #[test]
+    fn test_parse_sensor() {
+        let sensor = Sensor::from_line(Line::new("Sensor at x=12, y=14: closest beacon is at x=-10, y=16")).unwrap();
+
+        assert_eq!(sensor.pos   , Point::new( 12, 14));
+        assert_eq!(sensor.beacon, Point::new(-10, 16));
+
+        let error = Sensor::from_line(Line::new("Sensor at x=12, y=14: closest beacon is at x=-10 y=16")).err().unwrap();
+        assert_eq!(error.column, 49);
+
+        assert_eq!(Input::from_string("").err().unwrap().expected, "a sensor");
+    }
+
+    #[test]
+    fn test_row_coverage() {
+        let sensors = [Sensor { pos: Point::new(0, 0), beacon: Point::new(2, 0) }];
+
+        // the beacon's own row, one above it and one out of the sensor's reach
+        assert_eq!(row_coverage(&sensors, 0), 4);
+        assert_eq!(row_coverage(&sensors, 1), 3);
+        assert_eq!(row_coverage(&sensors, 5), 0);
+    }
+
+    #[test]
+    fn test_get_x_range_on_y_for_sensor() {
+        let sensor = Sensor {
+            pos   : Point::new(8,  7),
+            beacon: Point::new(2, 10)
+        };
+
+        assert_eq!(get_x_range_on_y_for_sensor(-2, &sensor), Some(8..=8));
+        assert_eq!(get_x_range_on_y_for_sensor(-1, &sensor), Some(7..=9));
+        assert_eq!(get_x_range_on_y_for_sensor( 7, &sensor), Some(-1..=17));
+
+        // test out of the sensor's range
+        assert_eq!(get_x_range_on_y_for_sensor(-3, &sensor), None);
+        assert_eq!(get_x_range_on_y_for_sensor(17, &sensor), None);
+    }
+
+    fn get_example() -> Input {
+        Day15::parse(common::example!()).unwrap()
+    }
+
+    fn get_input() -> Input {
+        Day15::parse(&common::input!()).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+/*  $ time target/release/day_15.exe
+    Part 1: 4883971
+    Part 2: 12691026767556
+
+    real    0m2.653s
+*/
@@ -0,0 +1,538 @@
+/*  https://adventofcode.com/2022/day/17  */
+
+use common::{Answer, Palette, ParseError, Render, Solution, Visualizer};
+use common::parse::{end_of_input, Line};
+use geometry::Point;
+use grid::Grid;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+
+    // the top of the tower as each of part 1's rocks comes to rest
+    fn visualize(input: &Input, visualizer: &mut Visualizer) -> bool {
+        let shapes = shapes();
+        let jets: Vec<char> = input.jet_pattern.chars().collect();
+
+        let mut tower = Tower::new();
+
+        for rock in 1..=2022 {
+            drop_rock(&mut tower, &shapes, &jets);
+            visualizer.show(format_args!("rock {} of 2022", rock), &tower);
+        }
+
+        true
+    }
+}
+
+pub struct Input { jet_pattern: String }
+
+// rows count up from the floor here, so y is the height above it
+type Shape = Vec<Point>;
+
+#[derive(Clone)]
+struct Chamber {
+    grid: Grid<bool>
+}
+
+// everything that decides how the next rock falls: the top of the chamber so far, and how
+// far through the rocks and the jets we are
+#[derive(Clone)]
+struct Tower {
+    chamber: Chamber,
+
+    // the rows dropped off the bottom of the chamber, which no rock can reach any more
+    below  : usize,
+
+    rock   : usize,
+    jet    : usize
+}
+
+
+// simulate falling rocks until we have 2022 settled rocks
+fn part1(input: &Input) -> usize {
+    let mut chamber = Chamber::new();
+    let shapes = shapes();
+    
+    // count how many rocks come to a stop
+    let mut stopped = 0;
+
+    // our repeating iterators
+    let mut rocks = repeat(shapes.iter());
+    let mut jet   = repeat(input.jet_pattern.chars());
+    
+    while stopped <= 2022 {
+        let rock = rocks.next().unwrap();
+
+        // start a new rock 3 rows above the pile
+        let mut location = Point::new(
+            2,                                // for some reason this gives the wrong answer if
+            chamber.top_row() as i64 + 3      // i use top_occupied_row() instead of top_row(),
+        );                                    // there shouldn't be a difference
+
+        loop {
+            let jet = jet.next().unwrap();
+
+            chamber.move_by_jet(rock, &mut location, jet);
+
+            if chamber.move_down(rock, &mut location) {
+                continue
+            }
+
+            // it couldn't move down, so lock it in place
+            chamber.situate(rock, location);
+            break;
+        }
+
+        stopped += 1;
+
+        common::trace!("stopped {}, rows {}", stopped, chamber.top_occupied_row());
+    }
+
+    chamber.top_occupied_row()
+}
+
+// a trillion rocks is too many to drop, but once the rock and jet patterns line up with
+// the same shape of skyline the tower grows the same way forever after. given time for
+// the skyline to settle into the rows kept, a few goes through every pairing of rock and
+// jet is plenty to find it, so the search gives up after that rather than run forever
+fn part2(input: &Input) -> Result<usize, String> {
+    let shapes = shapes();
+    let jets: Vec<char> = input.jet_pattern.chars().collect();
+
+    let step  = |tower: &mut Tower| drop_rock(tower, &shapes, &jets);
+    let limit = shapes.len() * (KEEP + 10 * jets.len());
+
+    let cycle = cycle::brent_within(Tower::new(), step, Tower::fingerprint, limit)
+                      .ok_or(format!("the tower doesn't repeat itself within {} rocks", limit))?;
+
+    // drop enough rocks to go once round the cycle, keeping the height after each
+    let mut tower   = Tower::new();
+    let mut heights = vec![0];
+
+    for _ in 0..cycle.start + cycle.period {
+        step(&mut tower);
+        heights.push(tower.height() as i64);
+    }
+
+    Ok(cycle.extrapolate(1_000_000_000_000, |n| heights[n]) as usize)
+}
+
+// drop the next rock until it comes to a stop, the same way as part 1
+fn drop_rock(tower: &mut Tower, shapes: &[Shape], jets: &[char]) {
+    let chamber = &mut tower.chamber;
+
+    let rock = &shapes[tower.rock];
+    let mut location = Point::new(2, chamber.top_row() as i64 + 3);
+
+    loop {
+        chamber.move_by_jet(rock, &mut location, jets[tower.jet]);
+        tower.jet = (tower.jet + 1) % jets.len();
+
+        if !chamber.move_down(rock, &mut location) { break }
+    }
+
+    chamber.situate(rock, location);
+    tower.rock = (tower.rock + 1) % shapes.len();
+
+    // only keep the top of the chamber
+    let extra = chamber.grid.height().saturating_sub(KEEP);
+
+    chamber.grid.drop_rows(extra);
+    tower.below += extra;
+}
+
+
+/* Tower */
+
+// how many rows from the top of the tower to draw
+const VIEW: usize = 30;
+
+// how many rows from the top of the chamber a tower keeps. rocks only ever come to rest
+// a few rows down, so the ones below are dropped, which keeps a tower the same size
+// however tall it grows
+const KEEP: usize = 128;
+
+impl Tower {
+    fn new() -> Self {
+        Tower { chamber: Chamber::new(), below: 0, rock: 0, jet: 0 }
+    }
+
+    fn height(&self) -> usize {
+        self.below + self.chamber.top_row()
+    }
+
+    // rocks can only land on the top of the pile, so the skyline (how far down from the
+    // top each column's highest rock is) stands in for the whole chamber. a rock could
+    // in theory slide in under an overhang, but it doesn't happen with these shapes. the
+    // depths only go as far down as the rows kept, so a column that never fills can't
+    // stop the tower repeating
+    fn fingerprint(&self) -> (usize, usize, [usize; WIDTH]) {
+        let grid = &self.chamber.grid;
+        let mut skyline = [0; WIDTH];
+
+        for (x, depth) in skyline.iter_mut().enumerate() {
+            *depth = match grid.column(x).rposition(|&filled| filled) {
+                Some(y) => grid.height() - 1 - y,
+                None    => grid.height()
+            };
+        }
+
+        (self.rock, self.jet, skyline)
+    }
+}
+
+impl Render for Tower {
+    // drawn like the puzzle, but only the top of it, with the floor once it's in view
+    fn render(&self) -> String {
+        let grid = &self.chamber.grid;
+        let mut text = String::new();
+
+        for y in (grid.height().saturating_sub(VIEW)..grid.height()).rev() {
+            let row: String = grid.row(y).iter()
+                                         .map(|&filled| if filled { '#' } else { '.' })
+                                         .collect();
+            text += &format!("|{}|\n", row);
+        }
+
+        if self.below == 0 && grid.height() <= VIEW {
+            text += &format!("+{}+\n", "-".repeat(WIDTH));
+        }
+
+        text
+    }
+
+    fn palette(&self) -> Palette {
+        let wall = [96, 96, 96];
+        Palette::new([0, 0, 0], &[('#', [200, 120, 64]), ('|', wall), ('+', wall), ('-', wall)])
+    }
+}
+
+
+/* Chamber */
+
+const WIDTH: usize = 7;
+
+const LEFT : Point = Point::new(-1,  0);
+const RIGHT: Point = Point::new( 1,  0);
+const DOWN : Point = Point::new( 0, -1);
+
+impl Chamber {
+    fn move_by_jet(&self, rock    : &Shape,
+                          location: &mut Point,
+                          jet     : char) -> bool
+    {
+        let relative = match jet {
+            '>' => RIGHT,
+            '<' => LEFT,
+             _  => panic!("unknown jet direction")
+        };
+
+        self.move_if_possible(rock, location, relative)
+    }
+
+    fn move_down(&self, rock    : &Shape,
+                        location: &mut Point) -> bool
+    {
+        self.move_if_possible(rock, location, DOWN)
+    }
+
+    // move if there's room to and return true if we did
+    fn move_if_possible(&self, rock    : &Shape,
+                               location: &mut Point,
+                               relative: Point) -> bool
+    {
+        if self.can_move(rock, *location, relative) {
+            *location += relative;
+            return true
+        }
+
+        false
+    }
+
+    fn can_move(&self, rock    : &Shape,
+                       location: Point,
+                       relative: Point) -> bool
+    {
+        rock.iter()
+            .all(|&pos| self.is_space(location + pos + relative))
+    }
+
+    fn is_space(&self, pos: Point) -> bool {
+        if pos.y < 0 { return false }
+        if pos.x < 0 { return false }
+        if pos.x >= WIDTH as i64 { return false }
+
+        if pos.y as usize >= self.grid.height() { return true }
+
+        ! self.grid[pos]
+    }
+
+    // lock a rock into place by filling its grid coordinates with true
+    fn situate(&mut self, rock    : &Shape,
+                          location: Point)
+    {
+        for &pos in rock {
+            let pos = pos + location;
+
+            // allocate space above as necessary
+            while pos.y as usize >= self.grid.height() {
+                self.grid.push_row(vec![false; WIDTH]);
+            }
+
+            self.grid[pos] = true;
+        }
+    }
+
+    // return the highest row index of a stopped rock
+    fn top_occupied_row(&self) -> usize {
+        if self.grid.height() == 0 { return 0 }
+
+        for row in (0..self.grid.height()-1).rev() {
+            if self.grid.row(row).iter()
+                             .any(|cell| *cell)
+            {
+                return row
+            }
+        }
+
+        0
+    }
+    
+    fn top_row(&self) -> usize {
+        self.grid.height()
+    }
+
+    fn new() -> Self {
+        Chamber { grid: Grid::new(WIDTH, 0, false) }
+    }
+}
+
+fn shapes() -> Vec<Shape> {
+
+    // ####
+    let line_across = vec![
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ];
+
+    // .#.
+    // ###
+    // .#.
+    let cross = vec![
+        Point::new(1, 2),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 0),
+    ];
+
+    // ..#
+    // ..#
+    // ###
+    let l = vec![
+        Point::new(2, 2),
+        Point::new(2, 1),
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+    ];
+
+    // #
+    // #
+    // #
+    // #
+    let line_down = vec![
+        Point::new(0, 3),
+        Point::new(0, 2),
+        Point::new(0, 1),
+        Point::new(0, 0),
+    ];
+
+    // ##
+    // ##
+    let square = vec![
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(0, 0),
+        Point::new(1, 0),
+    ];
+
+    vec![line_across, cross, l, line_down, square]
+}
+
+
+/* Repeater Iterator  */
+
+struct Repeater<I: Iterator> {
+    iter: I,
+    holding: Vec<I::Item>,
+    idx: usize
+}
+
+impl<I> Iterator for Repeater<I>
+where
+    I: Iterator,
+    I::Item: Copy  // the item type doesn't matter as long as we can make copies of it
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        match self.iter.next() {
+
+            // make copies of the items before returning them so when the underlying
+            // iterator runs out we can start returning clones of them
+            Some(item) => {
+                self.holding.push(item);
+                Some(item)
+            },
+
+            // underlying iterator is exhausted, so start iterating over
+            // the copies we made
+            None => {
+                let next = self.holding[self.idx];
+
+                self.idx += 1;
+                if self.idx >= self.holding.len() {
+                    self.idx = 0;
+                }
+
+                Some(next)
+            }
+        }
+    }
+}
+
+// take an iterator and return a new one that repeats that iterator's items forever
+fn repeat<I: Iterator>(iter: I) -> Repeater<I> {
+    Repeater {
+        iter,
+        holding: vec![],
+        idx: 0
+    }
+}
+
+
+/* Parsing */
+
+impl Input {
+    // the jets only ever push left or right
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        if let Some(index) = s.find(|c| c != '<' && c != '>') {
+            return Err(Line::new(s).error(index + 1, "a jet of '<' or '>'"))
+        }
+
+        // the rocks are pushed by the jets over and over, so there has to be at least one
+        if s.is_empty() {
+            return Err(end_of_input(s, "a jet of '<' or '>'"))
+        }
+
+        Ok(Input {
+            jet_pattern: s.to_string()
+        })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeater_iterator() {
+        let vec: Vec<i32> = vec![1,2,3];
+
+        let mut repeater = repeat(vec.iter());
+
+        assert_eq!(repeater.next(), Some(&1));
+        assert_eq!(repeater.next(), Some(&2));
+        assert_eq!(repeater.next(), Some(&3));
+        assert_eq!(repeater.next(), Some(&1));
+        assert_eq!(repeater.next(), Some(&2));
+        assert_eq!(repeater.next(), Some(&3));
+        assert_eq!(repeater.next(), Some(&1));
+        assert_eq!(repeater.next(), Some(&2));
+    }
+
+    #[test]
+    fn test_can_move() {
+        let rock = &shapes()[0];
+        let location = Point::new(0, 41);
+
+        let chamber = Chamber::new();
+
+        assert!(! chamber.can_move(rock, location, LEFT))
+    }
+
+    #[test]
+    fn test_part1() {
+       assert_eq!(part1(&get_example()), 3068);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Input::from_string("<<x>").err().unwrap().column, 3);
+        assert_eq!(Input::from_string("").err().unwrap().expected, "a jet of '<' or '>'");
+    }
+
+    #[test]
+    fn test_drop_rock() {
+        let shapes = shapes();
+        let jets: Vec<char> = get_example().jet_pattern.chars().collect();
+
+        let mut tower = Tower::new();
+        for _ in 0..2022 {
+            drop_rock(&mut tower, &shapes, &jets);
+        }
+
+        assert_eq!(tower.height(), 3068);
+
+        // only the top of the chamber is kept
+        assert_eq!(tower.chamber.grid.height(), KEEP);
+        assert_eq!(tower.below, 3068 - KEEP);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        Day17::visualize(&get_example(), &mut visualizer);
+
+        let frames = visualizer.recorded();
+        assert_eq!(frames.len(), 2022);
+
+        assert_eq!(frames[1].text, "|...#...|\n\
+                                    |..###..|\n\
+                                    |...#...|\n\
+                                    |..####.|\n\
+                                    +-------+\n");
+
+        assert_eq!(frames[2021].text.lines().count(), VIEW);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), Ok(1514285714288));
+
+        // every rock pushed right against the wall, so the left column never fills and its
+        // depth would grow forever if it weren't capped. each round of five rocks adds 13 rows
+        let right = Input::from_string(">").unwrap();
+        assert_eq!(part2(&right), Ok(2_600_000_000_000));
+    }
+
+    fn get_example() -> Input {
+        Day17::parse(common::example!()).unwrap()
+    }
+}
@@ -0,0 +1,155 @@
+/*  https://adventofcode.com/2022/day/18  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{end_of_input, lines, Line, Scanner};
+use geometry::Point3;
+use std::collections::HashSet;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { cubes : Vec<Cube> }
+
+// a cube is named by the position of its corner nearest the origin
+type Cube = Point3;
+
+// count the number of exposed cube sides, ie no other cube is adjacent to that side
+fn part1(input: &Input) -> usize {
+    let mut exposed = 0;
+
+    for cube in &input.cubes {
+        exposed += 6 - cube.neighbours6()
+                           .filter(|c| input.cubes.contains(c))
+                           .count();
+    }
+
+    exposed
+}
+
+// only consider the external surfaces that can be reached (flood fill and count cube sides)
+fn part2(input: &Input) -> usize {
+
+    // shift everything up diagonally so we can start exploring at the origin and know
+    // there isn't already cube there, and so the flood fill can get around the outside edges
+    let cubes: Vec<Cube> = input.cubes.iter()
+                                      .map(|&c| c + Cube::new(1, 1, 1))
+                                      .collect();
+
+    let x_bound = input.cubes.iter().map(|cube| cube.x).max().unwrap() + 2;
+    let y_bound = input.cubes.iter().map(|cube| cube.y).max().unwrap() + 2;
+    let z_bound = input.cubes.iter().map(|cube| cube.z).max().unwrap() + 2;
+
+    // contour the outside of the lava drop
+    count_faces(&cubes,
+                x_bound,
+                y_bound,
+                z_bound)
+}
+
+// flood fill from the cube at the origin and count how many times we run into a cube face
+fn count_faces(cubes: &[Cube], x: i64, y: i64, z: i64) -> usize {
+    let cubes: HashSet<Cube> = cubes.iter().copied().collect();
+
+    let in_bounds = |cube: &Cube|    cube.x >= 0 && cube.y >= 0 && cube.z >= 0
+                                  && cube.x <= x && cube.y <= y && cube.z <= z;
+
+    // every bit of air the water can get to from the origin
+    let air = search::bfs(Cube::ORIGIN, |cursor: &Cube| {
+        cursor.neighbours6()
+              .filter(|cube| in_bounds(cube) && !cubes.contains(cube))
+              .collect::<Vec<Cube>>()
+    });
+
+    // each side of the air touching a cube is a face of the outside of the lava drop
+    air.reached()
+       .map(|(cursor, _)| cursor.neighbours6().filter(|c| cubes.contains(c)).count())
+       .sum()
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let cubes: Vec<Cube> = lines(s).map(cube_from).collect::<Result<_, _>>()?;
+
+        if cubes.is_empty() {
+            return Err(end_of_input(s, "a cube"))
+        }
+
+        Ok(Input { cubes })
+    }
+}
+
+// "2,1,5"
+fn cube_from(line: Line) -> Result<Cube, ParseError> {
+    let mut scanner = line.scanner();
+
+    // part 2's flood fill goes round the drop from just below the origin, so the cubes
+    // can't be any lower than it
+    let coordinate = |scanner: &mut Scanner| {
+        let column = scanner.column();
+
+        match scanner.number()? {
+            n if n >= 0 => Ok(n),
+            _ => Err(scanner.error_at(column, "a coordinate of 0 or more"))
+        }
+    };
+
+    let x = coordinate(&mut scanner)?;  scanner.tag(",")?;
+    let y = coordinate(&mut scanner)?;  scanner.tag(",")?;
+    let z = coordinate(&mut scanner)?;
+    scanner.end()?;
+
+    Ok(Cube::new(x, y, z))
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_example()), 64);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_example()), 58);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Input::from_string("2,2,2\n1,2").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let error = Input::from_string("2,-2,2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+
+        assert_eq!(Input::from_string("").err().unwrap().expected, "a cube");
+    }
+
+    fn get_example() -> Input {
+        Day18::parse(common::example!()).unwrap()
+    }
+}
+
+/*  $ time target/release/day_18.exe
+    Part 1: 4400
+    Part 2: 2522
+
+    real    0m0.290s
+*/
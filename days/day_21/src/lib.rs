@@ -0,0 +1,391 @@
+/*  https://adventofcode.com/2022/day/21  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{end_of_input, lines, Line, Scanner};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input { jobs : HashMap<Monkey, Job> }
+
+type Monkey = String;
+
+enum Job {
+    Number(i64),
+    Calc(String, char, String)
+}
+
+// simple recursive descent into an expression tree, evaluating the top node
+fn part1(input: &Input) -> i64 {
+    yell("root", &input.jobs)
+}
+
+fn yell(monkey: &str,
+        jobs  : &HashMap<Monkey, Job>) -> i64
+{
+    match jobs.get(monkey).unwrap() {
+        // yell out the number
+        Job::Number(n) => *n,
+
+        // recursively compute the number
+        Job::Calc(left, operation, right) => {
+            let left  = yell(left, jobs);
+            let right = yell(right, jobs);
+
+            match operation {
+                '+' => left + right,
+                '-' => left - right,
+                '*' => left * right,
+                '/' => left / right,
+                 _  => panic!("unknown operation")
+            }
+        }
+    }
+}
+
+// in part 2, consider root's operation to be == and calculate what the "humn:" leaf
+// node would have to yell out to make root's two sub-expressions equal
+fn part2(input: &Input) -> i64 {
+
+    match input.jobs.get("root").unwrap() {
+        // evaluate both of root's sub-expressions, one must evaluate to a constant and
+        // the other a function that takes that constant and returns what 'humn' must be
+        Job::Calc(left, _, right) => {
+            let left  = call(left, &input.jobs);
+            let right = call(right, &input.jobs);
+
+            match (left, right) {
+                (Constant(n), Calc(f)) => f(n),
+                (Calc(f), Constant(n)) => f(n),
+
+                (Constant(_), Constant(_)) => panic!("didn't find a 'humn' node anywhere"),
+                (Calc(_), Calc(_))         => panic!("found 'humn' node on both sides of tree")
+            }
+        },
+        Job::Number(_) => panic!("expected the root node to be a calculation but it's a number")
+    }
+}
+
+fn call(monkey: &str,
+        jobs  : &HashMap<Monkey, Job>) -> ResultOfDescent
+{
+    // start off our big composed function with the identity function
+    if monkey == "humn" {
+        return Calc(Box::new(|x| x))
+    }
+
+    match jobs.get(monkey).unwrap() {
+        Job::Number(n) => Constant(*n),
+
+        Job::Calc(left, operation, right) => {
+            let left  = call(left, jobs);
+            let right = call(right, jobs);
+
+            // combine the left/right nodes in the 4 possible ways
+            match (left, right) {
+                (Constant(left), Constant(right)) => {
+                    match operation {
+                        '+' => Constant(left + right),
+                        '-' => Constant(left - right),
+                        '*' => Constant(left * right),
+                        '/' => Constant(left / right),
+                         _  => panic!("unknown operation")
+                    }
+                },
+                (Constant(n), Calc(f)) => {
+                    match operation {
+                        '+' => Calc(Box::new(move |x| f(x - n))), // assert x >= n?
+                        '-' => Calc(Box::new(move |x| f(n - x))),
+                        '*' => Calc(Box::new(move |x| f(x / n))),
+                        '/' => Calc(Box::new(move |x| f(n / x))),
+                         _  => panic!("unknown operation")
+                    }
+                },
+                (Calc(f), Constant(n)) => {
+                    match operation {
+                        '+' => Calc(Box::new(move |x| f(x - n))),
+                        '-' => Calc(Box::new(move |x| f(n + x))),
+                        '*' => Calc(Box::new(move |x| f(x / n))),
+                        '/' => Calc(Box::new(move |x| f(n * x))),
+                         _  => panic!("unknown operation")
+                    }
+                },
+                (Calc(_), Calc(_)) =>
+                    panic!("should never get here if there's only one 'humn' node in the tree"),
+            }
+        }
+    }
+}
+
+// the result of evaluating a sub-expression is either a constant or a function
+// that takes a number requested from above and calculates the missing operand
+enum ResultOfDescent {
+    Constant(i64),
+    Calc(Box<dyn Fn(i64) -> i64>)
+}
+
+use ResultOfDescent::*;
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = lines(s).collect();
+        let jobs: HashMap<Monkey, Job> = lines.iter()
+                                              .map(|&line| Input::from_line(line))
+                                              .collect::<Result<_, _>>()?;
+
+        if !jobs.contains_key("root") {
+            return Err(end_of_input(s, "a job for root"))
+        }
+
+        Input::check_operands(&lines, &jobs)?;
+
+        Ok(Input { jobs })
+    }
+
+    // every monkey a job waits on has to have a job of its own, and none can end up waiting
+    // on itself, or yelling would never finish. a depth first walk from each monkey finds
+    // any loop, without recursing so a long chain of monkeys can't overflow the stack
+    fn check_operands(lines: &[Line], jobs: &HashMap<Monkey, Job>) -> Result<(), ParseError> {
+        fn name_of<'a>(line: &Line<'a>) -> &'a str { line.text.split(':').next().unwrap() }
+
+        let line_of: HashMap<&str, Line> = lines.iter().map(|line| (name_of(line), *line)).collect();
+
+        // "abcd: efgh + ijkl", the operands are at fixed columns after the monkey's name
+        let operands = |monkey: &str| -> Vec<(&str, usize)> {
+            match &jobs[monkey] {
+                Job::Number(_) => vec![],
+                Job::Calc(left, _, right) => vec![(right.as_str(), monkey.len() + left.len() + 6),
+                                                  (left.as_str(),  monkey.len() + 3)]
+            }
+        };
+
+        for line in lines {
+            if let Some((name, column)) = operands(name_of(line)).into_iter().find(|(name, _)| !jobs.contains_key(*name)) {
+                return Err(line.error(column, format!("a monkey with a job, not {}", name)))
+            }
+        }
+
+        // monkeys walked from already, and the ones on the path down from the current start
+        let mut seen   : HashSet<&str> = HashSet::new();
+        let mut on_path: HashSet<&str> = HashSet::new();
+
+        for start in lines.iter().map(name_of) {
+            if !seen.insert(start) { continue }
+
+            // each monkey on the path with the operands still to go down into
+            let mut path = vec![(start, operands(start))];
+            on_path.insert(start);
+
+            while let Some((monkey, rest)) = path.last_mut() {
+                let monkey = *monkey;
+
+                match rest.pop() {
+                    Some((name, column)) => {
+                        if on_path.contains(name) {
+                            return Err(line_of[monkey].error(column, format!("a monkey that doesn't wait on {}", monkey)))
+                        }
+
+                        if seen.insert(name) {
+                            on_path.insert(name);
+                            path.push((name, operands(name)));
+                        }
+                    },
+                    None => {
+                        on_path.remove(monkey);
+                        path.pop();
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // "root: pppw + sjmn"
+    fn from_line(line: Line) -> Result<(String, Job), ParseError> {
+        let mut scanner = line.scanner();
+
+        let monkey = scanner.word()?;
+        scanner.tag(": ")?;
+        let job = Job::from(&mut scanner, monkey == "root")?;
+        scanner.end()?;
+
+        Ok((monkey.to_string(), job))
+    }
+}
+
+impl Job {
+    // "10"
+    // "pppw + sjmn"
+    fn from(scanner: &mut Scanner, is_root: bool) -> Result<Self, ParseError> {
+        if scanner.peek().is_some_and(|c| c.is_ascii_digit()) {
+            return Ok(Job::Number(scanner.number()?))
+        }
+
+        let left = scanner.word()?;
+        scanner.tag(" ")?;
+
+        // root's operation becomes an equality check in part 2, so it can be anything
+        let operation = if is_root { scanner.char()? } else { scanner.one_of("+-*/")? };
+
+        scanner.tag(" ")?;
+        let right = scanner.word()?;
+
+        Ok(Job::Calc(left.to_string(), operation, right.to_string()))
+    }
+}
+
+
+/* Imports */
+
+use std::collections::{HashMap, HashSet};
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_call() {
+
+        // simplest case, it will ignore the 99 and return 5
+        assert_eq!(part2(&Input::from_string("root: humn + five\n\
+                                              humn: 99\n\
+                                              five: 5").unwrap()),
+                         5);
+
+        // n + f
+        assert_eq!(part2(&Input::from_string("root: fifty . add\n\
+                                              add: thirtyfive + plus\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              five: 5\n\
+                                              fifty: 50\n\
+                                              humn: 0").unwrap()),
+                         10);
+
+        // n - f
+        assert_eq!(part2(&Input::from_string("root: five . sub\n\
+                                              sub: thirtyfive - plus\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              five: 5\n\
+                                              humn: 0").unwrap()),
+                         25);
+
+        // n * f
+        assert_eq!(part2(&Input::from_string("root: threefifty . mult\n\
+                                              mult: thirtyfive * plus\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              threefifty: 350\n\
+                                              five: 5\n\
+                                              humn: 0").unwrap()),
+                         350/35-5);
+
+        // n / f
+        assert_eq!(part2(&Input::from_string("root: five . div\n\
+                                              div: thirtyfive / plus\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              five: 5\n\
+                                              humn: 0").unwrap()),
+                         2);
+
+        // f + n
+        assert_eq!(part2(&Input::from_string("root: fifty . add\n\
+                                              add: plus + thirtyfive\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              five: 5\n\
+                                              fifty: 50\n\
+                                              humn: 0").unwrap()),
+                         10);
+
+        // f - n
+        assert_eq!(part2(&Input::from_string("root: five . sub\n\
+                                              sub: plus - thirtyfive\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              five: 5\n\
+                                              humn: 0").unwrap()),
+                         35);
+
+        // f * n
+        assert_eq!(part2(&Input::from_string("root: threefifty . mult\n\
+                                              mult: plus * thirtyfive\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              threefifty: 350\n\
+                                              five: 5\n\
+                                              humn: 0").unwrap()),
+                         350/35-5);
+
+        // f / n
+        assert_eq!(part2(&Input::from_string("root: five . div\n\
+                                              div: plus / thirtyfive\n\
+                                              thirtyfive: 35\n\
+                                              plus: humn + five\n\
+                                              five: 5\n\
+                                              humn: 0").unwrap()),
+                         5*35-5);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Input::from_string("root humn + five").err().unwrap();
+        assert_eq!(error.column, 5);
+
+        let error = Input::from_string("root: humn + five\nfive: humn % five").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 12));
+
+        let error = Input::from_string("root: humn + five\nhumn: 5").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 14));
+        assert_eq!(error.expected, "a monkey with a job, not five");
+
+        // root waits on itself by way of abcd
+        let error = Input::from_string("root: humn + abcd\nhumn: 5\nabcd: humn * root").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 14));
+
+        let error = Input::from_string("root: root + root").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 7));
+    }
+
+    #[test] fn test_part1() { assert_eq!(part1(&get_example()), 152); }
+    #[test] fn test_part2() { assert_eq!(part2(&get_example()), 301); }
+
+    #[test] #[ignore = "needs the puzzle input in input.txt"]
+    fn test_part1_mine() { assert_eq!(part1(&get_input()), 142_707_821_472_432); }
+    #[test] #[ignore = "needs the puzzle input in input.txt"]
+    fn test_part2_mine() { assert_eq!(part2(&get_input()), 3_587_647_562_851); }
+
+    fn get_example() -> Input {
+        Day21::parse(common::example!()).unwrap()
+    }
+
+    fn get_input() -> Input {
+        Day21::parse(&common::input!()).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+/*  $ time target/release/day_21.exe
+    Part 1: 142707821472432
+    Part 2: 3587647562851
+
+    real    0m0.019s
+*/
@@ -0,0 +1,214 @@
+/*  https://adventofcode.com/2022/day/22  */
+
+use common::{Answer, ParseError, Solution};
+use common::parse::{end_of_input, lines, Line};
+use geometry::{Cardinal, Point};
+use grid::Grid;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+}
+
+pub struct Input {
+    map  : Map,
+    path : Vec<Move>
+}
+
+type Map = Grid<char>;
+
+#[derive(Debug, PartialEq)]
+enum Move {
+    Forward(usize),
+    TurnRight,
+    TurnLeft
+}
+
+// navigate according to the plan, wrapping around in the obvious way
+fn part1(input: &Input) -> usize {
+    let mut facing = Cardinal::East;
+
+    // start at the left-most open tile in the top row
+    let mut pos = Point::new(input.map.row(1).iter()
+                                             .position(|&c| c == '.')
+                                             .unwrap() as i64,
+                             1);
+
+    for m in input.path.iter() {
+        match m {
+            Move::Forward(mut steps) => {
+                
+                while steps > 0
+                {
+                    if let Some(new_pos) = step_to(&input.map, facing, pos) {
+                        pos = new_pos;
+                    } else {
+                        break
+                    }
+
+                    steps -= 1;
+                }
+            },
+            dir => facing = turn(facing, dir)
+        }
+    }
+
+      pos.y as usize * 1000
+    + pos.x as usize * 4
+    + value_of(facing)
+}
+
+// if we can make this step on the map, return the new coordinates, otherwise None
+fn step_to(map   : &Map,
+           facing: Cardinal,
+           pos   : Point) -> Option<Point>
+{
+    let mut new = pos + facing.vector();
+
+    // if this took us off the edge, wrap around
+    if map[new] == ' ' {
+        let (row, col) = new.to_row_col().unwrap();
+
+        match facing
+        {
+            Cardinal::East  => new.x = map.row(row).iter(). position(|c| !c.is_whitespace()).unwrap() as i64,
+            Cardinal::West  => new.x = map.row(row).iter().rposition(|c| !c.is_whitespace()).unwrap() as i64,
+
+            Cardinal::South => new.y = map.column(col)
+                                          .position(|c| !c.is_whitespace())
+                                          .unwrap() as i64,
+
+            Cardinal::North => new.y = map.column(col)
+                                          .rposition(|c| !c.is_whitespace())
+                                          .unwrap() as i64
+        };
+    }
+
+    // if we'd run into a wall, we can't make the step
+    if map[new] == '#' { return None }
+
+    Some(new)
+}
+
+fn turn(facing: Cardinal, turn: &Move) -> Cardinal {
+    match turn {
+        Move::TurnRight  => facing.turn_right(),
+        Move::TurnLeft   => facing.turn_left(),
+        Move::Forward(_) => panic!("can't turn forward")
+    }
+}
+
+fn value_of(facing: Cardinal) -> usize {
+    match facing {
+        Cardinal::East  => 0,
+        Cardinal::South => 1,
+        Cardinal::West  => 2,
+        Cardinal::North => 3
+    }
+}
+
+// use the same plan but consider the map an unfolded cube, wrap around accordingly
+fn part2(_input: &Input) -> u32 {
+    0
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = lines(s).collect();
+
+        // the map and the path are separated by a blank line, the path being the last line
+        match lines[..] {
+            [ref map @ .., blank, path] if blank.text.is_empty() && !map.is_empty() => {
+                Ok(Input {
+                     map: Input::map_from(map)?,
+                    path: Input::path_from(path)?
+                })
+            },
+            _ => Err(end_of_input(s, "a map, a blank line, then a path"))
+        }
+    }
+
+    fn map_from(lines: &[Line]) -> Result<Map, ParseError> {
+        for line in lines {
+            if let Some(index) = line.text.find(|c| !" .#".contains(c)) {
+                return Err(line.error(index + 1, "a tile of ' ', '.' or '#'"))
+            }
+        }
+
+        // pad the rows out to the right to make them all the same width
+        let width = lines.iter()
+                         .map(|line| line.text.chars().count())
+                         .max().unwrap();
+
+        let rows = lines.iter()
+                        .map(|line| {
+                            let mut row: Vec<char> = line.text.chars().collect();
+                            row.resize(width, ' ');
+                            row
+                        })
+                        .collect();
+
+        // then make sure there's spaces all around the exterior
+        Ok(Grid::from_rows(rows).unwrap().expanded(1, ' '))
+    }
+
+    // "10R5L5"
+    fn path_from(line: Line) -> Result<Vec<Move>, ParseError> {
+        let mut scanner = line.scanner();
+        let mut moves: Vec<Move> = vec![];
+
+        while !scanner.is_done() {
+            moves.push(
+                match scanner.peek() {
+                    Some('L') => { scanner.char()?; Move::TurnLeft  },
+                    Some('R') => { scanner.char()?; Move::TurnRight },
+                    Some(c) if c.is_ascii_digit() => Move::Forward(scanner.number()?),
+                    _ => return Err(scanner.error("a number of steps, 'L' or 'R'"))
+                });
+        }
+
+        Ok(moves)
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path() {
+        let expected = vec![
+            Move::Forward(10),
+            Move::TurnRight,
+            Move::Forward(5),
+            Move::TurnLeft,
+            Move::Forward(6),
+        ];
+        assert_eq!(Input::path_from(Line::new("10R5L6")), Ok(expected));
+
+        let error = Input::path_from(Line::new("10R5X6")).err().unwrap();
+        assert_eq!(error.column, 5);
+    }
+
+    #[test] fn test_part1() { assert_eq!(part1(&get_example()), 6032); }
+    #[test] #[ignore = "part 2 isn't solved yet"]
+    fn test_part2() { assert_eq!(part2(&get_example()), 5031); }
+
+    fn get_example() -> Input {
+        Day22::parse(common::example!()).unwrap()
+    }
+}
@@ -0,0 +1,312 @@
+/*  https://adventofcode.com/2022/day/23  */
+
+use common::{Answer, Palette, ParseError, Render, Solution, Visualizer};
+use common::parse::end_of_input;
+use geometry::{Cardinal, Ordinal, Point};
+use grid::Grid;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+
+    // the elves spreading out round by round until they stop, as in part 2
+    fn visualize(input: &Input, visualizer: &mut Visualizer) -> bool {
+        let grove = Grove::from(input.grove.clone());
+        simulate(grove, 2, visualizer);
+        true
+    }
+}
+
+pub struct Input { grove : Grid<char> }
+struct Grove { grid  : Grid<char> }
+
+// simulate 10 rounds, then count the number of empty tiles between elves
+fn part1(input: &Input) -> usize {
+    let grove = Grove::from(input.grove.clone());
+    simulate(grove, 1, &mut Visualizer::off())
+}
+
+// keep simulating until no elf gets to move during a round
+fn part2(input: &Input) -> usize {
+    let grove = Grove::from(input.grove.clone());
+    simulate(grove, 2, &mut Visualizer::off())
+}
+
+
+/* Simulate */
+
+fn simulate(mut grove: Grove, part: usize, visualizer: &mut Visualizer) -> usize {
+    let mut proposals = get_proposals();
+
+    visualizer.show("Initial State", &grove);
+
+    for round in 1.. {
+        grove.wrap_with_ground_tiles();
+
+        // first half of round, collect the coordinates the elves propose to move to
+        let proposed: HashMap<Point, Vec<Elf>> = grove.get_proposed_moves(&proposals);
+
+        // second half, an elf moves if they're the only one to propose moving to that tile
+        let mut elves_moved = false;
+
+        for (move_to, elves) in proposed.iter() {
+            // no elf moves to this position if more than one proposed to
+            if elves.len() > 1 { continue }
+
+            grove.move_elf(elves[0], *move_to);
+            elves_moved = true;
+        }
+
+        visualizer.show(format_args!("End of Round {}", round), &grove);
+
+        if part == 1 && round == 10  { break }
+        if part == 2 && !elves_moved { return round }
+
+        // move the first proposal in the list to the end
+        cycle(&mut proposals);
+    }
+
+    grove.count_empty_tiles()
+}
+
+// put the first item of a vector at the end
+fn cycle<T>(vec: &mut Vec<T>) {
+    assert!(!vec.is_empty());
+
+    let first = vec.remove(0);
+    vec.push(first);
+}
+
+
+/* Grove */
+
+impl Grove {
+    // wrap a rectangle of empty tiles around the grid. called at the start of
+    // every round to make sure there's enough space to index within bounds
+    fn wrap_with_ground_tiles(&mut self) {
+        self.grid = self.grid.expanded(1, GROUND);
+    }
+
+    // first half of the round gets the list of positions the elves want to move to
+    fn get_proposed_moves(&self, proposals: &[Proposal]) -> HashMap<Point, Vec<Elf>> {
+
+        // first build a vector of elves and their proposed moves
+        let mut moves: Vec<(Elf, Point)> = vec![];
+
+        for pos in self.grid.points() {
+            if self.grid[pos] == GROUND { continue }
+
+            if !self.elves_around(pos) { continue }
+
+            for Proposal { moving, criteria } in proposals.iter() {
+                if !self.elves_at(pos, criteria) {
+                    moves.push(
+                        (pos,
+                         pos + moving.vector())
+                    );
+                    break
+                }
+            }
+        }
+
+        // collect the moves into a hashmap to tally the number of proposals per position
+        let mut hashmap: HashMap<Point, Vec<Elf>> = HashMap::new();
+
+        for m in moves {
+            let elves = hashmap.entry(m.1)
+                               .or_default();
+            elves.push(m.0);
+        }
+
+        hashmap
+    }
+
+    // check if there are any elves immediately surrounding the elf at this pos
+    fn elves_around(&self, pos: Point) -> bool {
+        pos.neighbours8()
+           .any(|around| self.grid[around] == ELF)
+    }
+
+    // check if there are any elves in the given directions
+    fn elves_at(&self, pos: Point, directions: &[Ordinal]) -> bool {
+        directions.iter()
+                  .any(|direction| self.grid[pos + direction.vector()] == ELF)
+    }
+
+    // move an elf by mutably swapping a '#' and '.' in the grid
+    fn move_elf(&mut self, elf: Elf, to: Point) {
+        assert!(self.grid[to ] == GROUND);
+        assert!(self.grid[elf] == ELF);
+        self.grid[to ] = ELF;
+        self.grid[elf] = GROUND;
+    }
+
+    // bound the elves in the smallest rectangle that covers them all and count the empty tiles
+    fn count_empty_tiles(&self) -> usize {
+        let (left, right, top, bottom) = self.rectangle_around();
+
+        let mut empty_tiles = 0;
+    
+        for row in top..=bottom {
+        for col in left..=right {
+            if self.grid[(row, col)] == GROUND {
+                empty_tiles += 1;
+            }
+        }
+        }
+
+        empty_tiles
+    }
+
+    // get the smallest rectangle around all the elves
+    fn rectangle_around(&self) -> (usize, usize, usize, usize) {
+        let elves: Vec<Elf> = self.grid.points()
+                                       .filter(|&pos| self.grid[pos] == ELF)
+                                       .collect();
+
+        assert!( !elves.is_empty() );
+
+        let cols: Vec<usize> = elves.iter().map(|p| p.x as usize).collect();
+        let rows: Vec<usize> = elves.iter().map(|p| p.y as usize).collect();
+
+        let left   = *cols.iter().min().unwrap();
+        let right  = *cols.iter().max().unwrap();
+        let top    = *rows.iter().min().unwrap();
+        let bottom = *rows.iter().max().unwrap();
+
+        (left, right, top, bottom)
+    }
+}
+
+impl Render for Grove {
+    // the smallest rectangle around all the elves
+    fn render(&self) -> String {
+        let (left, right, top, bottom) = self.rectangle_around();
+
+        (top..=bottom).map(|row| (left..=right).map(|col| self.grid[(row, col)]).collect::<String>() + "\n")
+                      .collect()
+    }
+
+    fn palette(&self) -> Palette {
+        Palette::new([16, 32, 16], &[(ELF, [64, 224, 96])])
+    }
+}
+
+
+/* Static Data */
+
+// the four proposals listed in the problem description: move one way if there are no
+// elves in any of three directions that way
+fn get_proposals() -> Vec<Proposal> {
+    use Ordinal::*;
+
+    vec![
+        Proposal { moving: Cardinal::North, criteria: [NorthWest, North, NorthEast] },
+        Proposal { moving: Cardinal::South, criteria: [SouthWest, South, SouthEast] },
+        Proposal { moving: Cardinal::West,  criteria: [NorthWest, West,  SouthWest] },
+        Proposal { moving: Cardinal::East,  criteria: [NorthEast, East,  SouthEast] }
+    ]
+}
+
+
+/* Types */
+
+type Elf  = Point;
+
+const ELF    : char = '#';
+const GROUND : char = '.';
+
+struct Proposal {
+    moving  : Cardinal,
+    criteria: [Ordinal; 3]
+}
+
+
+/* Constructors */
+
+impl Grove {
+    fn from(grid: Grid<char>) -> Self {
+        Grove { grid }
+    }
+}
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let tile = |c| match c {
+            ELF | GROUND => Some(c),
+            _            => None
+        };
+
+        let grove = Grid::parse(s, tile, "an elf '#' or an empty tile '.'")?;
+
+        // the elves are bounded by a rectangle, which needs at least one of them
+        if !grove.iter().any(|(_, &tile)| tile == ELF) {
+            return Err(end_of_input(s, "an elf '#'"))
+        }
+
+        Ok(Input { grove })
+    }
+}
+
+
+/* Imports */
+
+use std::collections::HashMap;
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test] fn test_part1() { assert_eq!(part1(&get_example()), 110); }
+    #[test] fn test_part2() { assert_eq!(part2(&get_example()), 20); }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Input::from_string("..\n..").err().unwrap().expected, "an elf '#'");
+        assert_eq!(Input::from_string("").err().unwrap().expected, "an elf '#'");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        Day23::visualize(&get_example(), &mut visualizer);
+
+        let frames = visualizer.recorded();
+        assert_eq!(frames.len(), 21);
+        assert_eq!(frames[0].text, common::example!());
+        assert_eq!(frames[20].title, "End of Round 20");
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        Day23::visualize(&get_example(), &mut visualizer);
+
+        common::assert_snapshot!("snapshots/example.gif", &visualizer.gif());
+    }
+
+    fn get_example() -> Input {
+        Day23::parse(common::example!()).unwrap()
+    }
+}
+
+/*  $ time target/release/day_23.exe
+    Part 1: 4123
+    Part 2: 1029
+
+    real    0m1.309s
+*/
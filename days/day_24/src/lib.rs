@@ -0,0 +1,568 @@
+/*  https://adventofcode.com/2022/day/24  */
+
+use common::{Answer, Palette, ParseError, Render, Solution, Visualizer};
+use common::parse::{end_of_input, lines, Line};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
+
+    // the expedition picking its way through the blizzards on the first trip across
+    fn visualize(input: &Input, visualizer: &mut Visualizer) -> bool {
+        let (_, path) = fastest_path(&input.valley, 0, true).expect("the valley can be crossed");
+
+        for (minute, tile) in path.iter().enumerate() {
+            visualizer.show(format_args!("Minute {}", minute), &Expedition { valley: &input.valley, at: tile });
+        }
+
+        true
+    }
+}
+
+pub struct Input { valley: ValleyMap<bool> }
+
+// compute the fastest way through the valley without getting caught in a blizzard
+fn part1(input: &Input) -> usize {
+    // start out at the starting tile at the starting time
+    fastest_trip(&input.valley, 0, true)
+}
+
+
+/* ValleyMap */
+
+// special struct that accounts for each possible place in a valley at any given time,
+// including the start and goal positions
+struct ValleyMap<T> {
+    tiles: Vec<T>,
+
+    // size of the interior of the valley (blizzard area)
+    height: usize,
+    width:  usize,
+
+    // number of distinct blizzard maps. they repeat after width*height,
+    // even sooner if gcd(width, height) > 1
+    weather_maps: usize
+}
+
+// a tile is an index into our 3D grid (two space and one time). Start/Goal represent the
+// fixed start and goal positions, Valley is somewhere in the main blizzard area
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Tile {
+    Valley(usize, usize, usize), // time, row, col
+    Start(usize),                // time
+    Goal(usize)                  // time
+}
+
+// ValleyMap uses a custom indexing system because the grid isn't quite rectangle due to the
+// start and goal positions jutting out at the top/bottom, and because time is a dimension,
+// and we want to store it all in one linear vector for constant-time access to elements.
+// it's generic over the type parameter T, but all we need to track is which positions at
+// which times are covered in a blizzard (bool)
+impl<T> Index<&Tile> for ValleyMap<T> {
+    type Output = T;
+
+    fn index(&self, index: &Tile) -> &Self::Output {
+        let generation_size =   self.width
+                              * self.height
+                              + 2; // start/goal positions
+
+        match index {
+            Tile::Valley(time, row, col)
+                              => &self.tiles[ (time % self.weather_maps) * generation_size
+                                              + row * self.width
+                                              + col ],
+
+            // tuck the start and goal cells right after the valley
+            Tile::Start(time) => &self.tiles [ (time % self.weather_maps + 1)
+                                               * generation_size - 2 ],
+
+            Tile::Goal(time)  => &self.tiles [ (time % self.weather_maps + 1)
+                                               * generation_size - 1 ],
+        }
+    }
+}
+
+// same as above but return a &mut ref to the tile so we can update it
+impl<T> IndexMut<&Tile> for ValleyMap<T> {
+    fn index_mut(&mut self, index: &Tile) -> &mut Self::Output {
+        let generation_size = self.width * self.height + 2;
+
+        match index {
+            Tile::Valley(time, row, col)
+                              => &mut self.tiles[ (time % self.weather_maps) * generation_size
+                                                  + row * self.width
+                                                  + col ],
+
+            Tile::Start(time) => &mut self.tiles [ (time % self.weather_maps + 1)
+                                                   * generation_size - 2 ],
+
+            Tile::Goal(time)  => &mut self.tiles [ (time % self.weather_maps + 1)
+                                                   * generation_size - 1 ],
+        }
+    }
+}
+
+// get the available (no blizzard) neighbours in a ValleyMap<bool> in the next unit of time
+fn get_neighbours(valley: &ValleyMap<bool>,
+                  tile  : &Tile) -> Vec<Tile>
+{
+    let mut tiles: Vec<Tile> = vec![];
+
+    let next = |time| (time+1) % valley.weather_maps;
+
+    // move forward one unit in time in all possible directions
+    use Tile::*;
+    match tile {
+        Valley(time, row, col) => {
+            // can we step south/north/east/west from here
+            if *row < valley.height-1 { tiles.push(Valley(next(time), *row+1, *col  )) }
+            if *row > 0               { tiles.push(Valley(next(time), *row-1, *col  )) }
+            if *col < valley.width-1  { tiles.push(Valley(next(time), *row  , *col+1)) }
+            if *col > 0               { tiles.push(Valley(next(time), *row  , *col-1)) }
+
+            // we can enter the start/goal positions if we're in the exact right cell
+            if *row == 0 && *col == 0 { tiles.push(Start(next(time))) }
+
+            if    *row == valley.height-1
+               && *col == valley.width-1 { tiles.push(Goal(next(time))) }
+        },
+        Start(time) => tiles.push(Valley(next(time), 0, 0)),
+        Goal(time)  => tiles.push(Valley(next(time), valley.height-1, valley.width-1))
+    }
+
+    // move forward in time but stay put at the current position
+    match tile {
+        Valley(time, row, col) => tiles.push(Valley(next(time), *row, *col)),
+        Start(time)            => tiles.push(Start(next(time))),
+        Goal(time)             => tiles.push(Goal(next(time)))
+    }
+
+    // those were all the possible moves given where we were on the board. we still have to
+    // account for the weather, so keep only the positions that are not blizzards
+    tiles.into_iter()
+         .filter(|tile| !valley[tile])
+         .collect()
+}
+
+// construct a ValleyMap<bool> from the input, with true wherever/whenever there's a blizzard
+fn valley_from_input(s: &str) -> Result<ValleyMap<bool>, ParseError> {
+    let lines = lines(s).collect::<Vec<Line>>();
+
+    // a wall along the top and the bottom with at least one row of valley in between
+    if lines.len() < 3 {
+        return Err(end_of_input(s, "a valley surrounded by walls"))
+    }
+
+    let weather = Weather::from_lines(&lines[1..lines.len()-1])?;
+    let height  = weather.height;
+    let width   = weather.width;
+
+    // the start is a gap in the top wall above the first column of the valley, and the
+    // goal one in the bottom wall below the last
+    wall(&lines[0], 1, width)?;
+    wall(&lines[lines.len()-1], width, width)?;
+
+    // the blizzards loop around forever and never turn, so a whole weather map
+    // will repeat after width*height time. if we're lucky we'll have gcd > 1
+    // which reduces our search space even further
+    let gcd = num::integer::gcd(height, width);
+    let weather_maps = height * width / gcd;
+
+    // allocate a tile for each position in the valley plus the start and goal
+    // positions, multiplied by the number of different weather pictures
+    let tiles: Vec<bool> = vec![ false; weather_maps * (height * width + 2) ];
+
+    let mut valley: ValleyMap<bool> = ValleyMap {
+        tiles,
+        height,
+        width,
+        weather_maps
+    };
+
+    // mark true when/where there's a blizzard. after these are set we don't have
+    // to keep track of the direction or number of blizzards on a tile, just that
+    // there was at least one blizzard there at that time
+    for (r, row) in weather.tiles.iter().enumerate() {
+        for (c, &tile) in row.iter().enumerate() {
+
+            match tile {
+                '>' => for time in 0..valley.weather_maps {
+                           let index = Tile::Valley(time, r, (time + c) % width);
+                           valley[&index] = true;
+                       },
+                '<' => for time in 0..valley.weather_maps {
+                           let index = Tile::Valley(time, r, ((c + time*width) - time) % width);
+                           valley[&index] = true;
+                       },
+                'v' => for time in 0..valley.weather_maps {
+                           let index = Tile::Valley(time, (time + r) % height, c);
+                           valley[&index] = true;
+                       },
+                '^' => for time in 0..valley.weather_maps {
+                           let index = Tile::Valley(time, ((r + time*height) - time) % height, c);
+                           valley[&index] = true;
+                       },
+                '.' => {},
+                 w  => panic!("unknown weather {w}")
+            }
+        }
+    }
+
+    // the blizzards can block the way for good, but if there's a way across from the first
+    // minute there's one from any later minute too, since waiting at either end is safe
+    if fastest_path(&valley, 0, true).is_none() {
+        return Err(end_of_input(s, "a way through the blizzards from the start to the goal"))
+    }
+
+    if fastest_path(&valley, 0, false).is_none() {
+        return Err(end_of_input(s, "a way through the blizzards from the goal to the start"))
+    }
+
+    Ok(valley)
+}
+
+// "#.#####" or "#####.#", a wall the width of the valley and its side walls with a single
+// gap in it at the given index
+fn wall(line: &Line, gap: usize, width: usize) -> Result<(), ParseError> {
+    let text = line.text;
+
+    if let Some(index) = text.chars().enumerate().position(|(index, c)| c != if index == gap { '.' } else { '#' }) {
+        let expected = if index == gap { "a gap '.'" } else { "a wall '#'" };
+        return Err(line.error(index + 1, expected))
+    }
+
+    if text.len() != width + 2 {
+        return Err(line.error(text.len().min(width + 2) + 1, format!("{} tiles in each row", width + 2)))
+    }
+
+    Ok(())
+}
+
+struct Weather {
+    tiles:  Vec<Vec<char>>,
+    height: usize,
+    width:  usize
+}
+
+impl Weather {
+    /*  #.....#
+        #>....#
+        #.....#
+        #...v.#
+        #.....#
+    */
+    fn from_lines(lines: &[Line]) -> Result<Self, ParseError> {
+        let width = lines[0].text.len();
+
+        // every row is the same width, walled in on both sides, with only blizzards inside
+        for line in lines {
+            let text = line.text;
+
+            if let Some(index) = text.find(|c| !"#.<>^v".contains(c)) {
+                return Err(line.error(index + 1, "a wall '#', ground '.' or a blizzard '<', '>', '^', 'v'"))
+            }
+
+            if text.len() < 3 || text.len() != width {
+                return Err(line.error(text.len().min(width) + 1, format!("{} tiles in each row", width.max(3))))
+            }
+
+            for index in [0, text.len() - 1] {
+                if &text[index..=index] != "#" {
+                    return Err(line.error(index + 1, "a wall '#'"))
+                }
+            }
+
+            if let Some(index) = text[1..text.len()-1].find('#') {
+                return Err(line.error(index + 2, "ground '.' or a blizzard '<', '>', '^', 'v'"))
+            }
+        }
+
+        let tiles: Vec<Vec<char>> = lines.iter()
+                                         .map(|line| &line.text[1..line.text.len()-1])
+                                         .map(|line| line.chars().collect())
+                                         .collect();
+
+        let width  = tiles[0].len();
+        let height = tiles.len();
+
+        Ok(Weather {
+            tiles,
+            width,
+            height
+        })
+    }
+}
+
+
+/* Searching the valley */
+
+// zig-zag from start to goal, back to start, then back to goal again
+fn part2(input: &Input) -> usize {
+    zig_zag(&input.valley, 3, true, 0)
+}
+
+fn zig_zag(valley         : &ValleyMap<bool>,
+           trips_left     : usize,
+           direction      : bool,
+           distance_so_far: usize) -> usize
+{
+    if trips_left == 0 {
+        return distance_so_far
+    }
+
+    let time = fastest_trip(valley, distance_so_far, direction);
+
+    zig_zag(valley,
+            trips_left - 1,
+            !direction,
+            distance_so_far + time)
+}
+
+// the fewest minutes to cross the valley starting at a given time, from the start to the
+// goal or the other way. every move takes a minute, and the number of steps left to
+// the far end if there were no blizzards is a guess that's never too high
+fn fastest_trip(valley   : &ValleyMap<bool>,
+                time     : usize,
+                direction: bool) -> usize
+{
+    fastest_path(valley, time, direction).expect("the valley can be crossed").0
+}
+
+// the same, along with every tile on the way, one for each minute. there's no way when
+// the blizzards block it for good, which the valley is checked for as it's parsed
+fn fastest_path(valley   : &ValleyMap<bool>,
+                time     : usize,
+                direction: bool) -> Option<(usize, Vec<Tile>)>
+{
+    let time = time % valley.weather_maps;
+    let (height, width) = (valley.height, valley.width);
+
+    let (start, to_goal): (Tile, fn(&Tile) -> bool) =
+        if direction { (Tile::Start(time), |tile| matches!(tile, Tile::Goal(_)))  }
+               else  { (Tile::Goal (time), |tile| matches!(tile, Tile::Start(_))) };
+
+    let steps_left = |tile: &Tile| {
+        let (row, col) = match tile {
+            Tile::Valley(_, row, col) => (*row + 1, *col),
+            Tile::Start(_)            => (0, 0),
+            Tile::Goal(_)             => (height + 1, width - 1)
+        };
+
+        if direction { (height + 1 - row) + (width - 1 - col) }
+               else  { row + col }
+    };
+
+    let neighbours = |tile: &Tile| get_neighbours(valley, tile).into_iter().map(|tile| (tile, 1));
+
+    search::astar(start, neighbours, steps_left, to_goal)
+}
+
+
+/* Visualization */
+
+// the valley at one moment on the way through it
+struct Expedition<'a> {
+    valley: &'a ValleyMap<bool>,
+    at    : &'a Tile
+}
+
+impl Render for Expedition<'_> {
+    // like the puzzle's maps, except all that's known about a blizzard is that it's there,
+    // so every one is a *. E is the expedition
+    fn render(&self) -> String {
+        let (height, width) = (self.valley.height, self.valley.width);
+
+        let time = match *self.at {
+            Tile::Valley(time, _, _) | Tile::Start(time) | Tile::Goal(time) => time
+        };
+
+        let start = if matches!(self.at, Tile::Start(_)) { 'E' } else { '.' };
+        let goal  = if matches!(self.at, Tile::Goal(_))  { 'E' } else { '.' };
+
+        let mut text = format!("#{}{}\n", start, "#".repeat(width));
+
+        for row in 0..height {
+            text.push('#');
+
+            for col in 0..width {
+                let tile = Tile::Valley(time, row, col);
+
+                let c = if *self.at == tile   { 'E' }
+                   else if self.valley[&tile] { '*' }
+                   else                       { '.' };
+
+                text.push(c);
+            }
+
+            text += "#\n";
+        }
+
+        text + &format!("{}{}#\n", "#".repeat(width), goal)
+    }
+
+    fn palette(&self) -> Palette {
+        Palette::new([16, 24, 40], &[('#', [80, 80, 88]), ('*', [200, 230, 255]), ('E', [255, 96, 32])])
+    }
+}
+
+
+/* Imports */
+
+use std::ops::{Index, IndexMut};
+
+
+/* Parsing */
+
+impl Input {
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        Ok(Input {
+            valley: valley_from_input(s)?
+        })
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weather_from_string() {
+        let weather = "#.....#\n\
+                       #>....#\n\
+                       #.....#\n\
+                       #...v.#\n\
+                       #.....#";
+
+        let weather = Weather::from_lines(&lines(weather).collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(weather.tiles.len(), 5);
+        assert_eq!(weather.tiles[1][0], '>');
+        assert_eq!(weather.tiles[1][1], '.');
+        assert_eq!(weather.tiles[3][3], 'v');
+        assert_eq!(weather.tiles[3].len(), 5);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Input::from_string("#.###\n#.x.#\n###.#").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Input::from_string("#.###\n#...\n###.#").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        // no way in, and a gap in the wrong place
+        let error = Input::from_string("#####\n#...#\n###.#").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 2, "a gap '.'"));
+
+        let error = Input::from_string("#.###\n#...#\n#.###").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (3, 2, "a wall '#'"));
+
+        let error = Input::from_string("#.####\n#...#\n###.#").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+
+        // a blizzard going up and down the only column, forever in the way
+        let error = Input::from_string("#.#\n#^#\n#.#").err().unwrap();
+        assert_eq!(error.expected, "a way through the blizzards from the start to the goal");
+    }
+
+    #[test] fn test_part1() { assert_eq!(part1(&get_example()), 18); }
+
+    #[test]
+    fn test_render() {
+        let mut visualizer = Visualizer::new(common::Mode::Record);
+        Day24::visualize(&get_example(), &mut visualizer);
+
+        let frames = visualizer.recorded();
+        assert_eq!(frames.len(), 19);
+
+        assert_eq!(frames[0].text, "#E######\n\
+                                    #**.***#\n\
+                                    #.*..**#\n\
+                                    #**.***#\n\
+                                    #******#\n\
+                                    ######.#\n");
+
+        assert!(frames[18].text.ends_with("######E#\n"));
+
+        common::assert_snapshot!("snapshots/example.gif", &visualizer.gif());
+    }
+    #[test] fn test_part2() { assert_eq!(part2(&get_example()), 54); }
+
+    #[test] #[ignore = "needs the puzzle input in input.txt"]
+    fn test_part1_mine() { assert_eq!(part1(&get_input()), 290); }
+    #[test] #[ignore = "needs the puzzle input in input.txt"]
+    fn test_part2_mine() { assert_eq!(part2(&get_input()), 842); }
+
+    #[test]
+    fn test_valley_from_weather_map() {
+        let valley = get_simple_valley();
+
+        // east-moving blizzard
+        assert!(!valley[&Tile::Valley(0, 1, 1)]); // there's not yet one to the east
+        assert!(valley[&Tile::Valley(0, 1, 0)]);
+        assert!(valley[&Tile::Valley(1, 1, 1)]);
+        assert!(valley[&Tile::Valley(2, 1, 2)]);
+        assert!(valley[&Tile::Valley(3, 1, 3)]);
+        assert!(valley[&Tile::Valley(4, 1, 4)]);
+        assert!(valley[&Tile::Valley(5, 1, 0)]); // time should wrap around to 0
+
+        // west
+        assert!(valley[&Tile::Valley(0, 2, 1)]);
+        assert!(valley[&Tile::Valley(1, 2, 0)]);
+        assert!(valley[&Tile::Valley(2, 2, 4)]);
+        assert!(valley[&Tile::Valley(3, 2, 3)]);
+        assert!(valley[&Tile::Valley(4, 2, 2)]);
+
+        // south
+        assert!(valley[&Tile::Valley(0, 3, 3)]);
+        assert!(valley[&Tile::Valley(1, 4, 3)]);
+        assert!(valley[&Tile::Valley(2, 0, 3)]);
+        assert!(valley[&Tile::Valley(3, 1, 3)]);
+        assert!(valley[&Tile::Valley(4, 2, 3)]);
+        assert!(valley[&Tile::Valley(5, 3, 3)]);
+
+        // north
+        assert!(valley[&Tile::Valley(0, 0, 2)]);
+        assert!(valley[&Tile::Valley(1, 4, 2)]);
+        assert!(valley[&Tile::Valley(2, 3, 2)]);
+        assert!(valley[&Tile::Valley(3, 2, 2)]);
+        assert!(valley[&Tile::Valley(4, 1, 2)]);
+        assert!(valley[&Tile::Valley(5, 0, 2)]);
+    }
+
+    fn get_example() -> Input {
+        Day24::parse(common::example!()).unwrap()
+    }
+
+    fn get_input() -> Input {
+        Day24::parse(&common::input!()).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn get_simple_valley() -> ValleyMap<bool> {
+        valley_from_input("#.#####\n\
+                           #..^..#\n\
+                           #>....#\n\
+                           #.<...#\n\
+                           #...v.#\n\
+                           #.....#\n\
+                           #####.#").unwrap()
+    }
+}
+
+/*  $ time target/release/day_24.exe
+    Part 1: 290
+    Part 2: 842
+
+    real    0m0.556s
+*/
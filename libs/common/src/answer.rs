@@ -0,0 +1,80 @@
+use std::fmt;
+
+// the days return all sorts of types for their answers (u32, usize, i64, String),
+// so they're gathered up into one type that the runner can treat the same way
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+
+    // an input the part couldn't find an answer for, and why
+    Unsolved(String)
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n)        => write!(f, "{}", n),
+            Answer::Text(s)          => write!(f, "{}", s),
+            Answer::Unsolved(reason) => write!(f, "unsolved: {}", reason)
+        }
+    }
+}
+
+macro_rules! from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(i64::try_from(n).expect("answer doesn't fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+// for the parts that can fail on an input that parsed fine
+impl<T: Into<Answer>> From<Result<T, String>> for Answer {
+    fn from(result: Result<T, String>) -> Self {
+        result.map_or_else(Answer::Unsolved, Into::into)
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(534_u32), Answer::Number(534));
+        assert_eq!(Answer::from(-3_i64), Answer::Number(-3));
+        assert_eq!(Answer::from("SHMSDGZVC"), Answer::Text("SHMSDGZVC".to_string()));
+
+        assert_eq!(Answer::from(Ok::<u32, String>(7)), Answer::Number(7));
+        assert_eq!(Answer::from(Err::<u32, String>("no cycle".to_string())), Answer::Unsolved("no cycle".to_string()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Number(14399640002).to_string(), "14399640002");
+        assert_eq!(Answer::Text("VRZGHDFBQ".to_string()).to_string(), "VRZGHDFBQ");
+        assert_eq!(Answer::Unsolved("no cycle".to_string()).to_string(), "unsolved: no cycle");
+    }
+}
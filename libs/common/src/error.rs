@@ -0,0 +1,88 @@
+use std::fmt;
+
+// returned when a puzzle input couldn't be parsed. it remembers where the problem was
+// and what the parser was expecting to find there, so a bad paste can be reported
+// like a compiler error instead of a panic with a backtrace:
+//
+//     error: expected a number
+//       --> day 5, line 12, column 6
+//        |
+//     12 | move x from 2 to 3
+//        |      ^
+//
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    // the day is filled in by Solution::load(), the parsers don't need to know it
+    pub day: Option<u32>,
+
+    // 1-based, like an editor shows them
+    pub line  : usize,
+    pub column: usize,
+
+    // what the parser wanted to see at that position, eg "a number" or "\" -> \""
+    pub expected: String,
+
+    // the text of the offending line, so we can point at the column
+    pub text: String
+}
+
+impl ParseError {
+    pub fn new(line    : usize,
+               column  : usize,
+               expected: impl Into<String>,
+               text    : &str) -> Self
+    {
+        ParseError {
+            day: None,
+            line,
+            column,
+            expected: expected.into(),
+            text: text.to_string()
+        }
+    }
+
+    pub fn in_day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        writeln!(f, "error: expected {}", self.expected)?;
+
+        match self.day {
+            Some(day) => writeln!(f, "{}--> day {}, line {}, column {}", gutter, day, self.line, self.column)?,
+            None      => writeln!(f, "{}--> line {}, column {}", gutter, self.line, self.column)?
+        }
+
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(12, 6, "a number", "move x from 2 to 3").in_day(5);
+
+        assert_eq!(error.to_string(),
+                   "error: expected a number\n  \
+                       --> day 5, line 12, column 6\n   \
+                      |\n\
+                    12 | move x from 2 to 3\n   \
+                      |      ^");
+    }
+}
@@ -0,0 +1,16 @@
+/*  Code shared by every day's solution  */
+
+mod answer;
+mod error;
+pub mod log;
+pub mod parse;
+mod render;
+mod solution;
+mod source;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use render::{check_snapshot, Frame, Mode, Render, Visualizer};
+pub use raster::Palette;
+pub use solution::{main, Solution};
+pub use source::Source;
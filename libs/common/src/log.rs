@@ -0,0 +1,238 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+// how much a message matters, from always worth seeing down to step by step detail
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace
+}
+
+impl Level {
+    // what -v, -vv and -vvv turn on. with none, only warnings and errors show
+    pub fn from_verbosity(count: u8) -> Self {
+        match count {
+            0 => Level::Warn,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Level::Error),
+            "warn"  => Ok(Level::Warn),
+            "info"  => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _       => Err(format!("expected error, warn, info, debug or trace, got \"{}\"", s))
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn  => "WARN",
+            Level::Info  => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE"
+        };
+
+        f.pad(name)
+    }
+}
+
+// which messages get written: everything up to one level, except for targets given
+// a level of their own. a target is the crate a message comes from, eg day19
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Filter {
+    default: Level,
+    targets: Vec<(String, Level)>
+}
+
+impl Filter {
+    pub fn new(default: Level) -> Self {
+        Filter { default, targets: vec![] }
+    }
+
+    // add directives separated by commas: a level on its own changes the default, and
+    // target=level changes just that target, as in "info,day19=debug"
+    pub fn parse(mut self, spec: &str) -> Result<Self, String> {
+        for directive in spec.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+            match directive.split_once('=') {
+                None                  => self.default = directive.parse()?,
+                Some((target, level)) => {
+                    let level  = level.parse()?;
+                    let target = target_name(target);
+
+                    self.targets.retain(|(name, _)| *name != target);
+                    self.targets.push((target, level));
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    pub fn level(&self, target: &str) -> Level {
+        self.targets.iter()
+                    .find(|(name, _)| name == target)
+                    .map_or(self.default, |&(_, level)| level)
+    }
+
+    // the most detailed level any target has, so anything past it can be skipped quickly
+    fn max_level(&self) -> Level {
+        self.targets.iter().map(|&(_, level)| level).fold(self.default, Level::max)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::new(Level::Warn)
+    }
+}
+
+// "day_19", "day19" and "day_19::module" are all day19, and "day1" is day01
+pub fn target_name(path: &str) -> String {
+    let name = path.split("::").next().unwrap_or("").replace('_', "");
+
+    match name.strip_prefix("day").and_then(|day| day.parse::<u32>().ok()) {
+        Some(day) => format!("day{:02}", day),
+        None      => name
+    }
+}
+
+
+/* Logging */
+
+// set by the runner, or else read from AOC_LOG the first time anything's logged
+static FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+
+// the filter's max_level() plus one, or 0 while there isn't a filter yet
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_filter(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8 + 1, Ordering::Relaxed);
+    *FILTER.write().unwrap() = Some(filter);
+}
+
+fn from_env() {
+    static ONCE: OnceLock<()> = OnceLock::new();
+
+    ONCE.get_or_init(|| {
+        if FILTER.read().unwrap().is_some() { return }
+
+        let spec   = std::env::var("AOC_LOG").unwrap_or_default();
+        let filter = Filter::default().parse(&spec).unwrap_or_else(|error| {
+            eprintln!("warning: ignoring AOC_LOG: {}", error);
+            Filter::default()
+        });
+
+        set_filter(filter);
+    });
+}
+
+// whether a message at this level from this module would be written. the log! macros
+// check this first so nothing gets formatted for a message nobody will see
+pub fn enabled(module: &str, level: Level) -> bool {
+    if MAX_LEVEL.load(Ordering::Relaxed) == 0 { from_env() }
+    if level as u8 + 1 > MAX_LEVEL.load(Ordering::Relaxed) { return false }
+
+    let filter = FILTER.read().unwrap();
+    filter.as_ref().is_some_and(|filter| level <= filter.level(&target_name(module)))
+}
+
+// messages go to stderr, a line each, so they never get mixed up with the answers
+pub fn write(module: &str, level: Level, message: fmt::Arguments) {
+    eprintln!("[{:<5} {}] {}", level, target_name(module), message);
+}
+
+// log!(Level::Debug, "...", ...), or one of the shorthands below for each level
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled(module_path!(), $level) {
+            $crate::log::write(module_path!(), $level, format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let filter = Filter::default().parse("info, day_19=debug,day1=trace").unwrap();
+
+        assert_eq!(filter.level("day19"), Level::Debug);
+        assert_eq!(filter.level("day01"), Level::Trace);
+        assert_eq!(filter.level("day17"), Level::Info);
+        assert_eq!(filter.max_level(), Level::Trace);
+
+        // a later directive for the same target wins
+        let filter = Filter::default().parse("day19=debug,day19=error").unwrap();
+        assert_eq!(filter.level("day19"), Level::Error);
+        assert_eq!(filter.level("day17"), Level::Warn);
+
+        assert_eq!(Filter::default().parse(""), Ok(Filter::default()));
+        assert!(Filter::default().parse("day19=loud").is_err());
+        assert!(Filter::default().parse("verbose").is_err());
+    }
+
+    #[test]
+    fn test_target_name() {
+        assert_eq!(target_name("day_19"), "day19");
+        assert_eq!(target_name("day_01::parse"), "day01");
+        assert_eq!(target_name("day7"), "day07");
+        assert_eq!(target_name("common::render"), "common");
+    }
+
+    #[test]
+    fn test_verbosity() {
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(9), Level::Trace);
+        assert!(Level::Error < Level::Trace);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::ParseError;
+use std::str::FromStr;
+
+// one line of a puzzle input, numbered from 1 so errors can point back at it
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text  : &'a str
+}
+
+impl<'a> Line<'a> {
+    // a lone line, handy in tests of the per-line parsers
+    pub fn new(text: &'a str) -> Self {
+        Line { number: 1, text }
+    }
+
+    pub fn scanner(&self) -> Scanner<'a> {
+        Scanner { line: *self, pos: 0 }
+    }
+
+    // an error pointing at a 1-based column of this line
+    pub fn error(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, expected, self.text)
+    }
+
+    // the whole line is a single number
+    pub fn number<T: FromStr>(&self) -> Result<T, ParseError> {
+        let mut scanner = self.scanner();
+        let number = scanner.number()?;
+        scanner.end()?;
+
+        Ok(number)
+    }
+}
+
+// the lines of the input, numbered
+pub fn lines(s: &str) -> impl Iterator<Item = Line<'_>> {
+    s.lines()
+     .enumerate()
+     .map(|(index, text)| Line { number: index + 1, text })
+}
+
+// the lines of the input grouped into blocks separated by blank lines, like day 1's elves
+// or day 11's monkeys
+pub fn blocks(s: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![vec![]];
+
+    for line in lines(s) {
+        if line.text.is_empty() {
+            blocks.push(vec![]);
+        } else {
+            blocks.last_mut().unwrap().push(line);
+        }
+    }
+
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+// an error for input that stopped early, pointing just after the last line
+pub fn end_of_input(s: &str, expected: impl Into<String>) -> ParseError {
+    let last = lines(s).last().unwrap_or(Line { number: 1, text: "" });
+
+    last.error(last.text.chars().count() + 1, expected)
+}
+
+
+/* Scanner */
+
+// walks along a line taking tokens off the front. every method either consumes what it
+// was asked for or returns an error saying what was expected at the current column
+pub struct Scanner<'a> {
+    line: Line<'a>,
+    pos : usize
+}
+
+impl<'a> Scanner<'a> {
+    // what's left of the line
+    pub fn rest(&self) -> &'a str {
+        &self.line.text[self.pos..]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn column(&self) -> usize {
+        self.line.text[..self.pos].chars().count() + 1
+    }
+
+    // an error at the current column
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.line.error(self.column(), expected)
+    }
+
+    // an error at an earlier column, for when a token parsed fine but its value is wrong
+    pub fn error_at(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        self.line.error(column, expected)
+    }
+
+    // indentation, like day 11's
+    pub fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches(' ').len();
+    }
+
+    // a literal piece of text, eg "move " or " -> "
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(tag) {
+            self.pos += tag.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("{:?}", tag)))
+        }
+    }
+
+    // the first of some alternative tags that matches, returning its index
+    pub fn tag_any(&mut self, tags: &[&str]) -> Result<usize, ParseError> {
+        match tags.iter().position(|tag| self.rest().starts_with(tag)) {
+            Some(index) => {
+                self.pos += tags[index].len();
+                Ok(index)
+            },
+            None => {
+                let tags: Vec<String> = tags.iter().map(|tag| format!("{:?}", tag)).collect();
+                Err(self.error(format!("one of {}", tags.join(", "))))
+            }
+        }
+    }
+
+    // a decimal number, possibly negative
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let len  = sign + rest[sign..].chars().take_while(char::is_ascii_digit).count();
+
+        match rest[..len].parse() {
+            Ok(number) if len > sign => {
+                self.pos += len;
+                Ok(number)
+            },
+            _ => Err(self.error("a number"))
+        }
+    }
+
+    // a run of letters and digits, eg a monkey's or a valve's name
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let len = rest.chars().take_while(char::is_ascii_alphanumeric).count();
+
+        if len == 0 { return Err(self.error("a name")) }
+
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    // any single character
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            },
+            None => Err(self.error("another character"))
+        }
+    }
+
+    // a single character out of a set, eg one of "RLUD"
+    pub fn one_of(&mut self, chars: &str) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) if chars.contains(c) => self.char(),
+            _ => {
+                let chars: Vec<String> = chars.chars().map(|c| format!("'{}'", c)).collect();
+                Err(self.error(format!("one of {}", chars.join(", "))))
+            }
+        }
+    }
+
+    // nothing else should follow on the line
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_done() {
+            Ok(())
+        } else {
+            Err(self.error("the end of the line"))
+        }
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let mut scanner = Line::new("move 12 from -3 to abc").scanner();
+
+        assert_eq!(scanner.tag("move "), Ok(()));
+        assert_eq!(scanner.number::<u32>(), Ok(12));
+        assert_eq!(scanner.tag_any(&[" to ", " from "]), Ok(1));
+        assert_eq!(scanner.number::<i32>(), Ok(-3));
+        assert_eq!(scanner.tag(" to "), Ok(()));
+        assert_eq!(scanner.word(), Ok("abc"));
+        assert_eq!(scanner.end(), Ok(()));
+    }
+
+    #[test]
+    fn test_scanner_errors() {
+        let line = Line { number: 12, text: "move x from 2 to 3" };
+        let mut scanner = line.scanner();
+
+        scanner.tag("move ").unwrap();
+
+        let error = scanner.number::<u32>().unwrap_err();
+        assert_eq!((error.line, error.column), (12, 6));
+        assert_eq!(error.expected, "a number");
+
+        let error = scanner.one_of("RL").unwrap_err();
+        assert_eq!(error.expected, "one of 'R', 'L'");
+
+        let error = scanner.tag(" -> ").unwrap_err();
+        assert_eq!(error.expected, "\" -> \"");
+
+        assert!(Line::new("-").number::<i32>().is_err());
+        assert!(Line::new("12a").number::<i32>().is_err());
+        assert!(Line::new("300").number::<u8>().is_err());
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = blocks("1\n2\n\n3\n\n\n4\n");
+
+        let numbers: Vec<Vec<usize>> = blocks.iter()
+                                             .map(|block| block.iter().map(|line| line.number).collect())
+                                             .collect();
+
+        assert_eq!(numbers, vec![vec![1, 2], vec![4], vec![7]]);
+    }
+
+    #[test]
+    fn test_end_of_input() {
+        let error = end_of_input("abc\ndef", "a blank line");
+
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
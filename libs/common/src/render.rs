@@ -0,0 +1,326 @@
+use raster::{Image, Palette};
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// a simulation that can draw its current state as text, one line per row
+pub trait Render {
+    fn render(&self) -> String;
+
+    // the colour each character is drawn in when frames are saved as pictures
+    fn palette(&self) -> Palette {
+        Palette::default()
+    }
+}
+
+// one drawing of a simulation, with a title saying when it was drawn
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    pub title: String,
+    pub text : String
+}
+
+// what a visualizer does with the frames it's shown
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Mode {
+    // nothing at all, for running a simulation normally
+    Off,
+
+    // print every frame one after another
+    Print,
+
+    // print a frame and wait for enter before carrying on
+    Step,
+
+    // draw each frame over the last one in the terminal, pausing between them
+    Animate(Duration),
+
+    // write every frame to its own numbered file in a directory
+    Dump(PathBuf),
+
+    // write every frame to its own numbered PNG in a directory
+    Png(PathBuf),
+
+    // save all the frames as one animated GIF
+    Gif(PathBuf),
+
+    // keep every frame in memory to look at afterwards
+    Record
+}
+
+const ANIMATION_DELAY: Duration = Duration::from_millis(50);
+
+// how many pixels across each character of a frame is in a picture
+const SCALE: usize = 4;
+
+impl Mode {
+    // --visualize prints every frame, or =step, =animate, =animate:<ms>, =dump:<dir>,
+    // =png:<dir> or =gif:<file> shows them another way
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        let usage = || format!("expected --visualize[=step|animate|animate:<ms>|dump:<dir>|png:<dir>|gif:<file>], got \"{}\"", arg);
+
+        let Some(mode) = arg.strip_prefix("--visualize") else { return Err(usage()) };
+
+        match mode {
+            ""         => Ok(Mode::Print),
+            "=step"    => Ok(Mode::Step),
+            "=animate" => Ok(Mode::Animate(ANIMATION_DELAY)),
+
+            _ => {
+                if let Some(ms) = mode.strip_prefix("=animate:") {
+                    let ms = ms.parse().map_err(|_| usage())?;
+                    Ok(Mode::Animate(Duration::from_millis(ms)))
+                }
+                else if let Some(dir) = mode.strip_prefix("=dump:").filter(|dir| !dir.is_empty()) {
+                    Ok(Mode::Dump(PathBuf::from(dir)))
+                }
+                else if let Some(dir) = mode.strip_prefix("=png:").filter(|dir| !dir.is_empty()) {
+                    Ok(Mode::Png(PathBuf::from(dir)))
+                }
+                else if let Some(file) = mode.strip_prefix("=gif:").filter(|file| !file.is_empty()) {
+                    Ok(Mode::Gif(PathBuf::from(file)))
+                }
+                else {
+                    Err(usage())
+                }
+            }
+        }
+    }
+}
+
+// the driver a simulation shows its frames to as it goes. it decides whether they're
+// printed, stepped through, animated or written out, so the simulation doesn't have to
+pub struct Visualizer {
+    mode    : Mode,
+    frames  : usize,
+    recorded: Vec<Frame>,
+
+    // the colours of the simulation being shown, from its first frame
+    palette : Option<Palette>,
+
+    // the first frame that couldn't be written out. nothing more is written after it
+    error   : Option<io::Error>
+}
+
+impl Visualizer {
+    pub fn new(mode: Mode) -> Self {
+        Visualizer { mode, frames: 0, recorded: vec![], palette: None, error: None }
+    }
+
+    // a visualizer that ignores everything it's shown, for solving the puzzle normally
+    pub fn off() -> Self {
+        Visualizer::new(Mode::Off)
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.mode != Mode::Off
+    }
+
+    // how many frames have been shown so far
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    // the frames kept by Mode::Record, or waiting to go in a GIF
+    pub fn recorded(&self) -> &[Frame] {
+        &self.recorded
+    }
+
+    // the recorded frames as an animated GIF, each one padded out to the size of the biggest
+    pub fn gif(&self) -> Vec<u8> {
+        let palette = self.palette.clone().unwrap_or_default();
+
+        let images: Vec<Image> = self.recorded.iter()
+                                              .map(|frame| Image::from_text(&frame.text, &palette, SCALE))
+                                              .collect();
+
+        let width  = images.iter().map(|image| image.width()).max().unwrap_or(0);
+        let height = images.iter().map(|image| image.height()).max().unwrap_or(0);
+
+        let images: Vec<Image> = images.iter().map(|image| image.padded(width, height)).collect();
+
+        raster::gif(&images, &palette, ANIMATION_DELAY)
+    }
+
+    // draw the simulation as it is now. it's only drawn at all if the visualizer is on, and
+    // the title is only written out then too, so a title from format_args! costs nothing
+    // when it's off
+    pub fn show(&mut self, title: impl fmt::Display, simulation: &impl Render) {
+        if !self.is_on() || self.error.is_some() { return }
+
+        self.frames += 1;
+
+        if self.palette.is_none() {
+            self.palette = Some(simulation.palette());
+        }
+
+        let frame = Frame { title: title.to_string(), text: simulation.render() };
+
+        if let Err(error) = self.output(frame) {
+            self.error = Some(error);
+        }
+    }
+
+    fn output(&mut self, frame: Frame) -> io::Result<()> {
+        let text = format!("== {} ==\n{}\n", frame.title, frame.text);
+
+        match &self.mode {
+            Mode::Off   => {},
+            Mode::Print => print!("{}", text),
+
+            Mode::Step => {
+                print!("{}(enter for the next frame)", text);
+                io::stdout().flush()?;
+                io::stdin().read_line(&mut String::new())?;
+            },
+
+            Mode::Animate(delay) => {
+                // clear the screen and go back to the top corner first
+                print!("\x1b[2J\x1b[H{}", text);
+                io::stdout().flush()?;
+                std::thread::sleep(*delay);
+            },
+
+            Mode::Dump(dir) => {
+                if self.frames == 1 { fs::create_dir_all(dir)? }
+                fs::write(dir.join(format!("frame_{:05}.txt", self.frames)), text)?;
+            },
+
+            Mode::Png(dir) => {
+                if self.frames == 1 { fs::create_dir_all(dir)? }
+
+                let palette = self.palette.as_ref().unwrap();
+                let image   = Image::from_text(&frame.text, palette, SCALE);
+                fs::write(dir.join(format!("frame_{:05}.png", self.frames)), raster::png(&image, palette))?;
+            },
+
+            Mode::Gif(_) | Mode::Record => self.recorded.push(frame)
+        }
+
+        Ok(())
+    }
+
+    // done showing frames. how many there were, or the error that stopped them being written
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(error) = self.error { return Err(error) }
+
+        // the GIF can only be put together once every frame's in
+        if let Mode::Gif(file) = &self.mode {
+            fs::write(file, self.gif())?;
+        }
+
+        Ok(self.frames)
+    }
+}
+
+
+/* Snapshots */
+
+// check output is the same as a file kept next to the calling crate's Cargo.toml, so a
+// change to it shows up as a failing test. set UPDATE_SNAPSHOTS=1 to write the file instead
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($file:expr, $bytes:expr) => {
+        $crate::check_snapshot(&std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($file), $bytes)
+    };
+}
+
+pub fn check_snapshot(path: &Path, bytes: &[u8]) {
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, bytes).unwrap();
+        return
+    }
+
+    let expected = fs::read(path).unwrap_or_else(|error| {
+        panic!("couldn't read the snapshot {}: {}, run with UPDATE_SNAPSHOTS=1 to write it", path.display(), error)
+    });
+
+    assert!(bytes == expected, "the output doesn't match {}, run with UPDATE_SNAPSHOTS=1 if it should have changed",
+            path.display());
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(usize);
+
+    impl Render for Counter {
+        fn render(&self) -> String {
+            "#".repeat(self.0) + "\n"
+        }
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Mode::from_arg("--visualize"), Ok(Mode::Print));
+        assert_eq!(Mode::from_arg("--visualize=step"), Ok(Mode::Step));
+        assert_eq!(Mode::from_arg("--visualize=animate"), Ok(Mode::Animate(ANIMATION_DELAY)));
+        assert_eq!(Mode::from_arg("--visualize=animate:200"), Ok(Mode::Animate(Duration::from_millis(200))));
+        assert_eq!(Mode::from_arg("--visualize=dump:frames"), Ok(Mode::Dump(PathBuf::from("frames"))));
+
+        assert_eq!(Mode::from_arg("--visualize=png:frames"), Ok(Mode::Png(PathBuf::from("frames"))));
+        assert_eq!(Mode::from_arg("--visualize=gif:rope.gif"), Ok(Mode::Gif(PathBuf::from("rope.gif"))));
+
+        assert!(Mode::from_arg("--visualize=animate:fast").is_err());
+        assert!(Mode::from_arg("--visualize=gif:").is_err());
+        assert!(Mode::from_arg("--visualize=dump:").is_err());
+        assert!(Mode::from_arg("--visualise").is_err());
+    }
+
+    #[test]
+    fn test_record() {
+        let mut visualizer = Visualizer::new(Mode::Record);
+
+        for n in 1..=3 {
+            visualizer.show(format_args!("step {}", n), &Counter(n));
+        }
+
+        assert_eq!(visualizer.frames(), 3);
+        assert_eq!(visualizer.recorded()[1], Frame { title: "step 2".to_string(), text: "##\n".to_string() });
+        assert_eq!(visualizer.finish().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_off() {
+        let mut visualizer = Visualizer::off();
+        visualizer.show("nothing", &Counter(1));
+
+        assert!(visualizer.recorded().is_empty());
+        assert_eq!(visualizer.finish().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_dump() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut visualizer = Visualizer::new(Mode::Dump(dir.clone()));
+
+        visualizer.show("one", &Counter(1));
+        visualizer.show("two", &Counter(2));
+        assert_eq!(visualizer.finish().unwrap(), 2);
+
+        assert_eq!(fs::read_to_string(dir.join("frame_00002.txt")).unwrap(), "== two ==\n##\n\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_gif() {
+        let file = std::env::temp_dir().join(format!("aoc-frames-{}.gif", std::process::id()));
+        let mut visualizer = Visualizer::new(Mode::Gif(file.clone()));
+
+        // frames of different sizes get padded to the biggest
+        visualizer.show("one", &Counter(1));
+        visualizer.show("two", &Counter(2));
+        assert_eq!(visualizer.finish().unwrap(), 2);
+
+        let gif = fs::read(&file).unwrap();
+        assert_eq!(&gif[..10], b"GIF89a\x08\0\x04\0");
+        fs::remove_file(file).unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Answer, Mode, ParseError, Source, Visualizer};
+use std::path::Path;
+
+// every day has the same shape: parse the puzzle input into some structure, then
+// run two different computations over it to get the two answers
+pub trait Solution {
+    // which day of the calendar this solves, so errors can say where they came from
+    const DAY: u32;
+
+    // the parsed puzzle input
+    type Input;
+
+    // build the input from the raw contents of the input file
+    fn parse(s: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    // run the day's simulation showing each step to the visualizer, for days that have one
+    // worth watching. false for the rest
+    fn visualize(_input: &Self::Input, _visualizer: &mut Visualizer) -> bool { false }
+
+    // parse, tagging any error with the day
+    fn load(s: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(s).map_err(|error| error.in_day(Self::DAY))
+    }
+}
+
+// the body of each day's main(): solve both parts for the input named on the command
+// line and print the answers, or with --visualize watch its simulation instead. dir is the
+// day's crate directory, where input.txt and example.txt are found
+pub fn main<S: Solution>(dir: &str) {
+    let (flags, args): (Vec<String>, Vec<String>) = std::env::args().skip(1)
+                                                                    .partition(|arg| arg.starts_with("--visualize"));
+
+    let mode = match flags.as_slice() {
+        []     => None,
+        [flag] => Some(Mode::from_arg(flag)),
+        _      => Some(Err("--visualize can only be given once".to_string()))
+    };
+
+    let mode = match mode.transpose() {
+        Ok(mode) => mode,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1)
+        }
+    };
+
+    let contents = match Source::from_args(&args, Path::new(dir)).and_then(|source| source.read()) {
+        Ok(contents) => contents,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1)
+        }
+    };
+
+    let input = match S::load(&contents) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1)
+        }
+    };
+
+    if let Some(mode) = mode {
+        return visualize::<S>(&input, mode)
+    }
+
+    print_answer(1, &S::part1(&input));
+    print_answer(2, &S::part2(&input));
+}
+
+fn visualize<S: Solution>(input: &S::Input, mode: Mode) {
+    let mut visualizer = Visualizer::new(mode);
+
+    if !S::visualize(input, &mut visualizer) {
+        eprintln!("error: day {} has no simulation to visualize", S::DAY);
+        std::process::exit(1)
+    }
+
+    if let Err(error) = visualizer.finish() {
+        eprintln!("error: couldn't write the frames: {}", error);
+        std::process::exit(1)
+    }
+}
+
+// multi-line answers (like day 10's CRT drawing) start on their own line
+fn print_answer(part: u32, answer: &Answer) {
+    let answer = answer.to_string();
+
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
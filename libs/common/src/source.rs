@@ -0,0 +1,112 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// where a day reads its puzzle input from
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+
+    // the example from the puzzle text, kept next to the day's Cargo.toml
+    Example(PathBuf)
+}
+
+impl Source {
+    // a day's command line is a path to read, - for stdin, or --example. with none at all it
+    // reads input.txt from the day's crate directory, wherever it's run from
+    pub fn from_args(args: &[String], dir: &Path) -> Result<Self, String> {
+        match args {
+            []                             => Ok(Source::File(dir.join("input.txt"))),
+            [arg] if arg == "-"            => Ok(Source::Stdin),
+            [arg] if arg == "--example"    => Ok(Source::Example(dir.join("example.txt"))),
+            [arg] if !arg.starts_with("-") => Ok(Source::File(PathBuf::from(arg))),
+            _                              => Err("usage: [input file | - | --example]".to_string())
+        }
+    }
+
+    // the input a buffer at a time, for days that stream it instead of reading it all in
+    pub fn open(&self) -> Result<Box<dyn Read>, String> {
+        match self {
+            Source::File(path) | Source::Example(path) => {
+                let file = std::fs::File::open(path).map_err(|error| format!("couldn't read {}: {}", path.display(), error))?;
+                Ok(Box::new(file))
+            },
+
+            Source::Stdin => Ok(Box::new(std::io::stdin().lock()))
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            Source::File(path) | Source::Example(path) => {
+                std::fs::read_to_string(path).map_err(|error| format!("couldn't read {}: {}", path.display(), error))
+            },
+
+            Source::Stdin => {
+                let mut contents = String::new();
+                std::io::stdin().read_to_string(&mut contents)
+                                .map_err(|error| format!("couldn't read stdin: {}", error))?;
+                Ok(contents)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::File(path) | Source::Example(path) => write!(f, "{}", path.display()),
+            Source::Stdin                              => write!(f, "stdin")
+        }
+    }
+}
+
+// the contents of an example file next to the calling crate's Cargo.toml, example.txt
+// unless another name is given. it's built in, so tests can run from anywhere
+#[macro_export]
+macro_rules! example {
+    ()           => { $crate::example!("example.txt") };
+    ($file:expr) => { include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $file)) };
+}
+
+// the puzzle input next to the calling crate's Cargo.toml. inputs aren't checked in, so
+// it's read when it's asked for instead of built in, for tests that are ignored without it
+#[macro_export]
+macro_rules! input {
+    () => {
+        std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("needs the puzzle input in input.txt")
+    };
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        let dir = Path::new("/aoc/days/day_10");
+
+        assert_eq!(Source::from_args(&args(&[]), dir), Ok(Source::File(dir.join("input.txt"))));
+        assert_eq!(Source::from_args(&args(&["-"]), dir), Ok(Source::Stdin));
+        assert_eq!(Source::from_args(&args(&["--example"]), dir), Ok(Source::Example(dir.join("example.txt"))));
+        assert_eq!(Source::from_args(&args(&["big.txt"]), dir), Ok(Source::File(PathBuf::from("big.txt"))));
+
+        assert!(Source::from_args(&args(&["--sample"]), dir).is_err());
+        assert!(Source::from_args(&args(&["a.txt", "b.txt"]), dir).is_err());
+    }
+
+    #[test]
+    fn test_read() {
+        let missing = Source::File(PathBuf::from("/no/such/input.txt"));
+        assert!(missing.read().err().unwrap().starts_with("couldn't read /no/such/input.txt"));
+        assert!(missing.open().err().unwrap().starts_with("couldn't read /no/such/input.txt"));
+    }
+}
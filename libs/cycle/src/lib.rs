@@ -0,0 +1,259 @@
+/*  Finding where a simulation starts repeating itself, to skip ahead to any step  */
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// a sequence of states that runs for a while and then goes round and round forever. the
+// state after start steps is the first one that comes back, period steps later
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start : usize,
+    pub period: usize
+}
+
+// all three take the first state, a step function that moves a state on to the next one,
+// and a fingerprint that's the same for two states exactly when they'll carry on the same
+// way. the states are stepped in place, so a big one isn't copied on every step. the
+// sequence has to cycle eventually, or they never return
+
+
+/* Brent */
+
+// steps the sequence about start + 2 * period times, and only ever keeps a couple of states
+pub fn brent<S, K>(initial    : S,
+                   step       : impl FnMut(&mut S),
+                   fingerprint: impl FnMut(&S) -> K) -> Cycle
+where
+    S: Clone,
+    K: Eq
+{
+    brent_within(initial, step, fingerprint, usize::MAX).expect("the sequence cycles")
+}
+
+// the same, but giving up with None once the hare has taken limit steps without finding the
+// cycle, for a sequence that might take too long to repeat or never do
+pub fn brent_within<S, K>(initial        : S,
+                          mut step       : impl FnMut(&mut S),
+                          mut fingerprint: impl FnMut(&S) -> K,
+                          limit          : usize) -> Option<Cycle>
+where
+    S: Clone,
+    K: Eq
+{
+    // the hare runs ahead, and the tortoise teleports to it every power of two steps
+    // until the hare laps it. how far it ran since the last teleport is the period
+    let mut power  = 1;
+    let mut period = 1;
+    let mut steps  = 1;
+
+    let mut tortoise = fingerprint(&initial);
+    let mut hare     = initial.clone();
+    step(&mut hare);
+
+    while fingerprint(&hare) != tortoise {
+        if steps >= limit { return None }
+
+        if power == period {
+            tortoise = fingerprint(&hare);
+            power *= 2;
+            period = 0;
+        }
+
+        step(&mut hare);
+        period += 1;
+        steps  += 1;
+    }
+
+    Some(Cycle { start: start_of(initial, period, step, fingerprint), period })
+}
+
+
+/* Floyd */
+
+// steps the sequence about 3 * (start + period) times
+pub fn floyd<S, K>(initial        : S,
+                   mut step       : impl FnMut(&mut S),
+                   mut fingerprint: impl FnMut(&S) -> K) -> Cycle
+where
+    S: Clone,
+    K: Eq
+{
+    // the hare runs at twice the speed of the tortoise, so they meet somewhere in the cycle
+    let mut tortoise = initial.clone();
+    step(&mut tortoise);
+
+    let mut hare = tortoise.clone();
+    step(&mut hare);
+
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+    }
+
+    // then the hare goes round once more on its own to time the period
+    let mut period = 1;
+    let meeting = fingerprint(&tortoise);
+    step(&mut hare);
+
+    while fingerprint(&hare) != meeting {
+        step(&mut hare);
+        period += 1;
+    }
+
+    Cycle { start: start_of(initial, period, step, fingerprint), period }
+}
+
+// with the period known, a hare that starts a period ahead of the tortoise meets it the
+// moment the tortoise walks into the cycle
+fn start_of<S, K>(initial        : S,
+                  period         : usize,
+                  mut step       : impl FnMut(&mut S),
+                  mut fingerprint: impl FnMut(&S) -> K) -> usize
+where
+    S: Clone,
+    K: Eq
+{
+    let mut tortoise = initial.clone();
+    let mut hare     = initial;
+
+    for _ in 0..period {
+        step(&mut hare);
+    }
+
+    let mut start = 0;
+
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    start
+}
+
+
+/* Hashing */
+
+// steps the sequence exactly start + period times, remembering every fingerprint on the
+// way. the state itself doesn't need to be cloneable
+pub fn hashed<S, K>(initial        : S,
+                    mut step       : impl FnMut(&mut S),
+                    mut fingerprint: impl FnMut(&S) -> K) -> Cycle
+where
+    K: Eq + Hash
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut state = initial;
+
+    for i in 0.. {
+        let key = fingerprint(&state);
+
+        if let Some(&first) = seen.get(&key) {
+            return Cycle { start: first, period: i - first }
+        }
+
+        seen.insert(key, i);
+        step(&mut state);
+    }
+
+    unreachable!()
+}
+
+
+/* Extrapolating */
+
+impl Cycle {
+    // the step in the first time round the cycle that's in the same state as step n
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start { n } else { self.start + (n - self.start) % self.period }
+    }
+
+    // a running total at step n, like the height of a tower, given its value at each of the
+    // steps up to start + period. it goes up by the same amount every time round the cycle
+    pub fn extrapolate(&self, n: usize, mut metric: impl FnMut(usize) -> i64) -> i64 {
+        let end = self.start + self.period;
+        if n <= end { return metric(n) }
+
+        let per_cycle = metric(end) - metric(self.start);
+        let cycles    = ((n - self.start) / self.period) as i64;
+
+        metric(self.equivalent(n)) + cycles * per_cycle
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // x -> x^2 + 1 mod m, the kind of sequence Pollard's rho walks. from 0 with m = 255:
+    // 0, 1, 2, 5, 26, 167, 95, 101, 2, ... so the cycle is 2, 5, 26, 167, 95, 101
+    fn squares(x: &mut u64) { *x = (*x * *x + 1) % 255 }
+
+    fn itself(x: &u64) -> u64 { *x }
+
+    #[test]
+    fn test_finders_agree() {
+        let expected = Cycle { start: 2, period: 6 };
+
+        assert_eq!(brent (0, squares, itself), expected);
+        assert_eq!(floyd (0, squares, itself), expected);
+        assert_eq!(hashed(0, squares, itself), expected);
+    }
+
+    #[test]
+    fn test_no_prefix() {
+        let around = |x: &mut u64| *x = (*x + 1) % 7;
+        let expected = Cycle { start: 0, period: 7 };
+
+        assert_eq!(brent (0, around, itself), expected);
+        assert_eq!(floyd (0, around, itself), expected);
+        assert_eq!(hashed(0, around, itself), expected);
+
+        // a fixed point is a cycle of one
+        assert_eq!(brent(3, |x| *x = 3, itself), Cycle { start: 0, period: 1 });
+        assert_eq!(floyd(3, |x| *x = 3, itself), Cycle { start: 0, period: 1 });
+    }
+
+    #[test]
+    fn test_within() {
+        // the hare laps the tortoise 13 steps in
+        assert_eq!(brent_within(0, squares, itself, 13), Some(Cycle { start: 2, period: 6 }));
+        assert_eq!(brent_within(0, squares, itself, 12), None);
+
+        // counting up forever never repeats
+        assert_eq!(brent_within(0, |x: &mut u64| *x += 1, itself, 1000), None);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // the state counts up forever, but only its last digit decides what comes next
+        let state = |x: &mut u64| *x += 1;
+        let digit = |x: &u64| x % 10;
+
+        assert_eq!(brent (25, state, digit), Cycle { start: 0, period: 10 });
+        assert_eq!(hashed(25, state, digit), Cycle { start: 0, period: 10 });
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // a total that goes up 1 then 2 then 3 and settles into +5, +1 forever from step 3
+        let totals = [0, 1, 3, 6, 11, 12];
+        let cycle  = Cycle { start: 3, period: 2 };
+
+        assert_eq!(cycle.equivalent(2), 2);
+        assert_eq!(cycle.equivalent(7), 3);
+        assert_eq!(cycle.equivalent(8), 4);
+
+        assert_eq!(cycle.extrapolate(4, |n| totals[n]), 11);
+        assert_eq!(cycle.extrapolate(7, |n| totals[n]), 18);
+        assert_eq!(cycle.extrapolate(8, |n| totals[n]), 23);
+
+        // the same thing by brute force, a long way out
+        let brute = (6..1001).fold(12, |total, n| total + if (n - 3) % 2 == 0 { 1 } else { 5 });
+        assert_eq!(cycle.extrapolate(1000, |n| totals[n]), brute);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::Point;
+
+// the four compass directions. north is up the screen, towards smaller y
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Cardinal {
+    North,
+    East,
+    South,
+    West
+}
+
+// the compass directions plus the four diagonals between them
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Ordinal {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
+}
+
+impl Cardinal {
+    // clockwise from north
+    pub const ALL: [Cardinal; 4] = [Cardinal::North, Cardinal::East, Cardinal::South, Cardinal::West];
+
+    // one step this way
+    pub fn vector(self) -> Point {
+        match self {
+            Cardinal::North => Point::new( 0, -1),
+            Cardinal::East  => Point::new( 1,  0),
+            Cardinal::South => Point::new( 0,  1),
+            Cardinal::West  => Point::new(-1,  0)
+        }
+    }
+
+    pub fn turn_right(self) -> Self { Cardinal::ALL[(self as usize + 1) % 4] }
+    pub fn turn_left(self)  -> Self { Cardinal::ALL[(self as usize + 3) % 4] }
+    pub fn opposite(self)   -> Self { Cardinal::ALL[(self as usize + 2) % 4] }
+}
+
+impl Ordinal {
+    // clockwise from north
+    pub const ALL: [Ordinal; 8] = [Ordinal::North, Ordinal::NorthEast, Ordinal::East, Ordinal::SouthEast,
+                                   Ordinal::South, Ordinal::SouthWest, Ordinal::West, Ordinal::NorthWest];
+
+    pub fn vector(self) -> Point {
+        match self {
+            Ordinal::North     => Point::new( 0, -1),
+            Ordinal::NorthEast => Point::new( 1, -1),
+            Ordinal::East      => Point::new( 1,  0),
+            Ordinal::SouthEast => Point::new( 1,  1),
+            Ordinal::South     => Point::new( 0,  1),
+            Ordinal::SouthWest => Point::new(-1,  1),
+            Ordinal::West      => Point::new(-1,  0),
+            Ordinal::NorthWest => Point::new(-1, -1)
+        }
+    }
+
+    // an eighth of a turn
+    pub fn turn_right(self) -> Self { Ordinal::ALL[(self as usize + 1) % 8] }
+    pub fn turn_left(self)  -> Self { Ordinal::ALL[(self as usize + 7) % 8] }
+    pub fn opposite(self)   -> Self { Ordinal::ALL[(self as usize + 4) % 8] }
+}
+
+impl From<Cardinal> for Ordinal {
+    fn from(direction: Cardinal) -> Self {
+        Ordinal::ALL[direction as usize * 2]
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(Cardinal::North.turn_right(), Cardinal::East);
+        assert_eq!(Cardinal::North.turn_left(),  Cardinal::West);
+        assert_eq!(Cardinal::East.opposite(),    Cardinal::West);
+
+        // turning the direction turns its vector the same way
+        for direction in Cardinal::ALL {
+            assert_eq!(direction.turn_right().vector(), direction.vector().turn_right());
+            assert_eq!(direction.opposite().vector(), -direction.vector());
+        }
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(Ordinal::NorthWest.turn_right(), Ordinal::North);
+        assert_eq!(Ordinal::North.turn_left(), Ordinal::NorthWest);
+        assert_eq!(Ordinal::SouthEast.opposite(), Ordinal::NorthWest);
+
+        for direction in Cardinal::ALL {
+            assert_eq!(Ordinal::from(direction).vector(), direction.vector());
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/*  Points and vectors on a 2D grid or in 3D space, and the directions between them  */
+
+mod direction;
+mod point;
+mod point3;
+
+pub use direction::{Cardinal, Ordinal};
+pub use point::Point;
+pub use point3::Point3;
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// a position on a 2D grid, or the vector between two of them. y grows downwards like the
+// rows of a puzzle's map do, so turning right from east heads south
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    // a grid's (row, column) position as a point
+    pub fn from_row_col((row, col): (usize, usize)) -> Self {
+        Point::new(col as i64, row as i64)
+    }
+
+    // the other way, None if it's above or left of the grid
+    pub fn to_row_col(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+
+    /* Distances */
+
+    // steps apart moving only up, down, left and right
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // steps apart when diagonal moves count as one, like a king in chess
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // each coordinate squashed to -1, 0 or 1, the single step in the direction of a vector
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+
+    /* Rotation */
+
+    // a quarter turn clockwise about the origin, as seen on screen
+    pub fn turn_right(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    pub fn turn_left(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
+    // any number of quarter turns, negative ones going anticlockwise
+    pub fn rotate(self, quarter_turns: i32) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => self.turn_right(),
+            2 => -self,
+            _ => self.turn_left()
+        }
+    }
+
+
+    /* Neighbours */
+
+    // the 4 points directly above, below, left and right
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        crate::Cardinal::ALL.into_iter().map(move |direction| self + direction.vector())
+    }
+
+    // the 8 points surrounding this one, including the diagonals
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        crate::Ordinal::ALL.into_iter().map(move |direction| self + direction.vector())
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+
+/* Arithmetic */
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point { Point::new(self.x + other.x, self.y + other.y) }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point { Point::new(self.x - other.x, self.y - other.y) }
+}
+
+impl Neg for Point {
+    type Output = Point;
+    fn neg(self) -> Point { Point::new(-self.x, -self.y) }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+    fn mul(self, n: i64) -> Point { Point::new(self.x * n, self.y * n) }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) { *self = *self + other }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) { *self = *self - other }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point::new(3, -2);
+
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(-p, Point::new(-3, 2));
+        assert_eq!(p * 3, Point::new(9, -6));
+
+        p += Point::new(-3, 2);
+        assert_eq!(p, Point::ORIGIN);
+        p -= Point::new(1, 0);
+        assert_eq!(p, Point::new(-1, 0));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(8, 7);
+        let b = Point::new(2, 10);
+
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let east = Point::new(1, 0);
+
+        assert_eq!(east.turn_right(), Point::new(0, 1));
+        assert_eq!(east.turn_left(),  Point::new(0, -1));
+        assert_eq!(Point::new(2, 1).rotate(2), Point::new(-2, -1));
+        assert_eq!(Point::new(2, 1).rotate(-1), Point::new(2, 1).turn_left());
+        assert_eq!(Point::new(2, 1).rotate(4), Point::new(2, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(Point::ORIGIN.neighbours4().count(), 4);
+        assert_eq!(Point::ORIGIN.neighbours8().filter(|p| p.chebyshev(Point::ORIGIN) == 1).count(), 8);
+    }
+
+    #[test]
+    fn test_row_col() {
+        assert_eq!(Point::from_row_col((2, 5)), Point::new(5, 2));
+        assert_eq!(Point::new(5, 2).to_row_col(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 2).to_row_col(), None);
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// a position in 3D space, or the vector between two of them
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64
+}
+
+// one step along each axis, either way
+const FACES: [Point3; 6] = [
+    Point3::new( 1,  0,  0), Point3::new(-1,  0,  0),
+    Point3::new( 0,  1,  0), Point3::new( 0, -1,  0),
+    Point3::new( 0,  0,  1), Point3::new( 0,  0, -1)
+];
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+
+    /* Distances */
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+                                .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+
+    /* Rotation */
+
+    // a quarter turn about each axis, right-handed: anticlockwise looking down the axis
+    // towards the origin
+    pub fn rotate_x(self) -> Self { Point3::new( self.x, -self.z,  self.y) }
+    pub fn rotate_y(self) -> Self { Point3::new( self.z,  self.y, -self.x) }
+    pub fn rotate_z(self) -> Self { Point3::new(-self.y,  self.x,  self.z) }
+
+
+    /* Neighbours */
+
+    // the 6 points sharing a face with this one
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        FACES.into_iter().map(move |face| self + face)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+
+/* Arithmetic */
+
+impl Add for Point3 {
+    type Output = Point3;
+    fn add(self, other: Point3) -> Point3 { Point3::new(self.x + other.x, self.y + other.y, self.z + other.z) }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+    fn sub(self, other: Point3) -> Point3 { Point3::new(self.x - other.x, self.y - other.y, self.z - other.z) }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+    fn neg(self) -> Point3 { Point3::new(-self.x, -self.y, -self.z) }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+    fn mul(self, n: i64) -> Point3 { Point3::new(self.x * n, self.y * n, self.z * n) }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Point3) { *self = *self + other }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, other: Point3) { *self = *self - other }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point3::new(1, -2, 3);
+
+        assert_eq!(p + Point3::new(1, 1, 1), Point3::new(2, -1, 4));
+        assert_eq!(p - p, Point3::ORIGIN);
+        assert_eq!(-p * 2, Point3::new(-2, 4, -6));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(4, 0, 3);
+
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!((b - a).signum(), Point3::new(1, -1, 0));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let p = Point3::new(1, 2, 3);
+
+        assert_eq!(Point3::new(0, 1, 0).rotate_x(), Point3::new(0, 0, 1));
+        assert_eq!(Point3::new(0, 0, 1).rotate_y(), Point3::new(1, 0, 0));
+        assert_eq!(Point3::new(1, 0, 0).rotate_z(), Point3::new(0, 1, 0));
+
+        assert_eq!(p.rotate_x().rotate_x().rotate_x().rotate_x(), p);
+        assert_eq!(p.rotate_y().rotate_y(), Point3::new(-1, 2, -3));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let around: Vec<Point3> = Point3::ORIGIN.neighbours6().collect();
+
+        assert_eq!(around.len(), 6);
+        assert!(around.iter().all(|p| p.manhattan(Point3::ORIGIN) == 1));
+    }
+}
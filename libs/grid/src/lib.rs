@@ -0,0 +1,383 @@
+/*  A rectangular grid of cells, stored flat, row by row  */
+
+use common::parse::{lines, Line};
+use common::ParseError;
+use geometry::Point;
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width : usize,
+    height: usize,
+    cells : Vec<T>
+}
+
+// up, down, left, right
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+// the orthogonals plus the four diagonals, clockwise from up-left
+pub const ALL_AROUND: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, 1),
+                                             ( 1,  1), ( 1, 0), ( 1, -1), (0, -1)];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    // None if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width  = rows.first().map_or(0, |row| row.len());
+
+        if rows.iter().any(|row| row.len() != width) { return None }
+
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    pub fn width(&self)  -> usize { self.width  }
+    pub fn height(&self) -> usize { self.height }
+
+
+    /* Indexing */
+
+    pub fn contains(&self, row: isize, col: isize) -> bool {
+           row >= 0 && (row as usize) < self.height
+        && col >= 0 && (col as usize) < self.width
+    }
+
+    // checked indexing, None off the edge of the grid
+    pub fn get(&self, row: isize, col: isize) -> Option<&T> {
+        if !self.contains(row, col) { return None }
+        Some(&self.cells[row as usize * self.width + col as usize])
+    }
+
+    pub fn get_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        if !self.contains(row, col) { return None }
+        Some(&mut self.cells[row as usize * self.width + col as usize])
+    }
+
+    // checked indexing by a point, x being the column and y the row
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.get(point.y as isize, point.x as isize)
+    }
+
+    pub fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.y as isize, point.x as isize)
+    }
+
+    // indexing that wraps around the edges, as if the grid were tiled forever
+    pub fn wrapping(&self, row: isize, col: isize) -> &T {
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let col = col.rem_euclid(self.width  as isize) as usize;
+
+        &self.cells[row * self.width + col]
+    }
+
+    // a position moved by an offset, if it's still on the grid
+    pub fn step(&self, (row, col): (usize, usize), (drow, dcol): (isize, isize)) -> Option<(usize, usize)> {
+        let (row, col) = (row as isize + drow, col as isize + dcol);
+
+        if self.contains(row, col) { Some((row as usize, col as usize)) } else { None }
+    }
+
+
+    /* Neighbours */
+
+    // the up to 4 positions directly above, below, left and right of a position
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&offset| self.step(pos, offset))
+    }
+
+    // the up to 8 positions surrounding a position, including the diagonals
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_AROUND.iter().filter_map(move |&offset| self.step(pos, offset))
+    }
+
+
+    /* Lines of cells */
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "column {} is off a grid {} wide", col, self.width);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    // the cells in a straight line from a position (not including it) to the edge of the
+    // grid, in any of the 8 directions. (1, 1) is the diagonal down and to the right
+    pub fn ray(&self, pos: (usize, usize), direction: (isize, isize)) -> impl Iterator<Item = &T> + '_ {
+        assert!(direction != (0, 0), "a ray needs a direction");
+
+        std::iter::successors(self.step(pos, direction), move |&pos| self.step(pos, direction))
+            .map(|pos| &self[pos])
+    }
+
+
+    /* Whole grid */
+
+    // every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |row| (0..self.width).map(move |col| (row, col)))
+    }
+
+    // every position as a point, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.positions().map(Point::from_row_col)
+    }
+
+    // every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width : self.width,
+            height: self.height,
+            cells : self.cells.iter().map(f).collect()
+        }
+    }
+
+    // a bigger grid with a border of fill all the way around
+    pub fn expanded(&self, border: usize, fill: T) -> Self
+    where
+        T: Clone
+    {
+        let mut grid = Grid::new(self.width + border * 2, self.height + border * 2, fill);
+
+        for ((row, col), cell) in self.iter() {
+            grid[(row + border, col + border)] = cell.clone();
+        }
+
+        grid
+    }
+
+    // add a row to the bottom of the grid
+    pub fn push_row(&mut self, row: Vec<T>) {
+        assert!(self.height == 0 || row.len() == self.width,
+                "a row of {} doesn't fit a grid {} wide", row.len(), self.width);
+
+        self.width = row.len();
+        self.height += 1;
+        self.cells.extend(row);
+    }
+
+    // take rows off the top of the grid, moving the rest up, for a grid that only needs to
+    // keep the rows most recently pushed
+    pub fn drop_rows(&mut self, count: usize) {
+        let count = count.min(self.height);
+
+        self.cells.drain(..count * self.width);
+        self.height -= count;
+    }
+
+
+    /* Text */
+
+    // one character per cell, one line per row
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>() + "\n")
+            .collect()
+    }
+
+
+    /* Parsing */
+
+    // one cell per character, with the cell function deciding what each character means.
+    // a character it returns None for is an error, and so is a row of a different length
+    pub fn parse(s: &str, cell: impl Fn(char) -> Option<T>, expected: &str) -> Result<Self, ParseError> {
+        Grid::from_lines(&lines(s).collect::<Vec<_>>(), cell, expected)
+    }
+
+    pub fn from_lines(lines: &[Line], cell: impl Fn(char) -> Option<T>, expected: &str) -> Result<Self, ParseError> {
+        let mut grid = Grid { width: 0, height: 0, cells: vec![] };
+
+        for line in lines {
+            let row = line.text.chars()
+                               .enumerate()
+                               .map(|(index, c)| cell(c).ok_or_else(|| line.error(index + 1, expected)))
+                               .collect::<Result<Vec<T>, _>>()?;
+
+            if grid.height > 0 && row.len() != grid.width {
+                let column = row.len().min(grid.width) + 1;
+                return Err(line.error(column, format!("{} cells in each row", grid.width)))
+            }
+
+            grid.push_row(row);
+        }
+
+        Ok(grid)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.height && col < self.width,
+                "({}, {}) is off a {}x{} grid", row, col, self.height, self.width);
+
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.height && col < self.width,
+                "({}, {}) is off a {}x{} grid", row, col, self.height, self.width);
+
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.at(point).unwrap_or_else(|| panic!("{} is off a {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.at_mut(point).unwrap_or_else(|| panic!("{} is off a {}x{} grid", point, width, height))
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit(c: char) -> Option<u32> { c.to_digit(10) }
+
+    fn get_example() -> Grid<u32> {
+        Grid::parse("123\n\
+                     456", digit, "a digit").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = get_example();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.row(0), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::parse("123\n4x6", digit, "a digit").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a digit");
+
+        let error = Grid::parse("123\n45", digit, "a digit").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "3 cells in each row");
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert_eq!(Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]), Some(get_example()));
+        assert_eq!(Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5]]), None);
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = get_example();
+
+        assert_eq!(grid.get(1, 2), Some(&6));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+
+        assert_eq!(grid.wrapping(-1, -1), &6);
+        assert_eq!(grid.wrapping(2, 4), &2);
+
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.at(Point::new(1, 2)), None);
+        grid[Point::new(2, 0)] = 7;
+
+        *grid.get_mut(0, 0).unwrap() = 9;
+        grid[(0, 1)] = 8;
+        assert_eq!(grid.row(0), &[9, 8, 7]);
+        assert_eq!(grid.get_mut(5, 5), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_off_the_grid() {
+        let _ = get_example()[(2, 0)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = get_example();
+
+        let mut around: Vec<u32> = grid.neighbours4((0, 1)).map(|pos| grid[pos]).collect();
+        around.sort();
+        assert_eq!(around, vec![1, 3, 5]);
+
+        let mut around: Vec<u32> = grid.neighbours8((0, 0)).map(|pos| grid[pos]).collect();
+        around.sort();
+        assert_eq!(around, vec![2, 4, 5]);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = get_example();
+
+        assert_eq!(grid.rows().next_back(), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), vec![6, 3]);
+
+        assert_eq!(grid.ray((0, 0), (0, 1)).copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(grid.ray((0, 0), (1, 1)).copied().collect::<Vec<_>>(), vec![5]);
+        assert_eq!(grid.ray((1, 2), (-1, -1)).copied().collect::<Vec<_>>(), vec![2]);
+        assert_eq!(grid.ray((1, 0), (1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_whole_grid() {
+        let grid = get_example();
+
+        assert_eq!(grid.iter().find(|(_, &n)| n == 5).map(|(pos, _)| pos), Some((1, 1)));
+        assert_eq!(grid.points().nth(4), Some(Point::new(1, 1)));
+        assert_eq!(grid.map(|n| n % 2 == 0).render(|&even| if even { '#' } else { '.' }),
+                   ".#.\n#.#\n");
+
+        let expanded = grid.expanded(1, 0);
+        assert_eq!((expanded.width(), expanded.height()), (5, 4));
+        assert_eq!(expanded.row(1), &[0, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_push_row() {
+        let mut grid: Grid<bool> = Grid::from_rows(vec![]).unwrap();
+        grid.push_row(vec![false; 7]);
+        grid.push_row(vec![true; 7]);
+
+        assert_eq!((grid.width(), grid.height()), (7, 2));
+        assert!(grid[(1, 6)]);
+
+        grid.drop_rows(1);
+        assert_eq!((grid.width(), grid.height()), (7, 1));
+        assert!(grid[(0, 6)]);
+
+        grid.drop_rows(5);
+        assert_eq!(grid.height(), 0);
+    }
+}
@@ -0,0 +1,330 @@
+/*  Sets of integers stored as sorted, non-overlapping inclusive ranges  */
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+// the integer types an interval can be made of. an interval set needs to know what comes
+// just before and after a number to tell when two ranges touch, like 1..=3 and 4..=6
+pub trait Endpoint: Copy + Ord {
+    // None at either end of the type, where there's no next or previous number
+    fn next(self) -> Option<Self>;
+    fn prev(self) -> Option<Self>;
+
+    // how many numbers from start to end inclusive, start being no more than end. a u128,
+    // as every number a u64 can hold is one more than a u64 can count to
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! endpoint {
+    ($($t:ty),*) => {$(
+        impl Endpoint for $t {
+            fn next(self) -> Option<Self> { self.checked_add(1) }
+            fn prev(self) -> Option<Self> { self.checked_sub(1) }
+
+            fn count(start: Self, end: Self) -> u128 {
+                (end as i128 - start as i128 + 1) as u128
+            }
+        }
+    )*}
+}
+
+endpoint!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// the ranges are kept sorted, with a gap of at least one number between each of them,
+// so there's exactly one way to store any set of numbers
+#[derive(Clone, Eq, PartialEq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<RangeInclusive<T>>
+}
+
+// whether a range ending at end runs right up to or past a range starting at start
+fn touches<T: Endpoint>(end: T, start: T) -> bool {
+    end.next().is_none_or(|next| next >= start)
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    pub fn intervals(&self) -> &[RangeInclusive<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // how many numbers are in the set. each range holds at most 2^64 of them, so it would
+    // take more ranges than fit in memory to run past a u128
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(|range| T::count(*range.start(), *range.end())).sum()
+    }
+
+    pub fn contains(&self, n: T) -> bool {
+        let index = self.intervals.partition_point(|range| *range.end() < n);
+        self.intervals.get(index).is_some_and(|range| *range.start() <= n)
+    }
+
+
+    /* Changing the set */
+
+    // add a range, merging it with any it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end { return }
+
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        let mut placed = false;
+
+        for range in self.intervals.drain(..) {
+            if touches(*range.end(), start) && touches(end, *range.start()) {
+                // swallow it into the new range
+                start = start.min(*range.start());
+                end   = end  .max(*range.end());
+            }
+            else if *range.end() < start {
+                intervals.push(range);
+            }
+            else {
+                // past the new range, so it goes in first
+                if !placed {
+                    intervals.push(start..=end);
+                    placed = true;
+                }
+                intervals.push(range);
+            }
+        }
+
+        if !placed { intervals.push(start..=end) }
+
+        self.intervals = intervals;
+    }
+
+    // take a range out, splitting any range it falls in the middle of
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end { return }
+
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+
+        for range in self.intervals.drain(..) {
+            if *range.end() < start || end < *range.start() {
+                intervals.push(range);
+                continue
+            }
+
+            // whatever's left sticking out either side
+            if *range.start() < start { intervals.push(*range.start()..=start.prev().unwrap()) }
+            if end < *range.end()     { intervals.push(end.next().unwrap()..=*range.end()) }
+        }
+
+        self.intervals = intervals;
+    }
+
+
+    /* Combining sets */
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+
+        for range in &other.intervals {
+            union.insert(range.clone());
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.intervals, &other.intervals);
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = vec![];
+
+        // walk both lists together, always moving past whichever range ends first
+        while i < a.len() && j < b.len() {
+            let start = *a[i].start().max(b[j].start());
+            let end   = *a[i].end()  .min(b[j].end());
+
+            if start <= end { intervals.push(start..=end) }
+
+            if a[i].end() < b[j].end() { i += 1 } else { j += 1 }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    // everything in this set that isn't in the other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+
+        for range in &other.intervals {
+            difference.remove(range.clone());
+        }
+
+        difference
+    }
+
+    // the gaps, everything within the bounds that isn't in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        IntervalSet::from(bounds).difference(self)
+    }
+}
+
+impl<T: Endpoint> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Endpoint> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+
+        for range in ranges {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+
+        // the new range is non-overlapping to the left of all the others
+        assert_eq!(set(&[3..=5, 7..=8, 1..=1]).intervals(), &[1..=1, 3..=5, 7..=8]);
+
+        // to the right of all the others
+        assert_eq!(set(&[3..=5, 7..=8, 10..=11]).intervals(), &[3..=5, 7..=8, 10..=11]);
+
+        // fits in between two existing ones, touching neither
+        assert_eq!(set(&[1..=3, 7..=8, 5..=5]).intervals(), &[1..=3, 5..=5, 7..=8]);
+
+        // overlaps two inner ones
+        assert_eq!(set(&[1..=2, 5..=6, 8..=9, 12..=13, 4..=8]).intervals(), &[1..=2, 4..=9, 12..=13]);
+
+        // engulfs all the others
+        assert_eq!(set(&[3..=5, 7..=8, 1..=9]).intervals(), &[1..=9]);
+
+        // touching ranges join up, there's nothing between them
+        assert_eq!(set(&[1..=2, 3..=5, 6..=7]).intervals(), &[1..=7]);
+
+        // an empty range adds nothing
+        let (start, end) = (5, 4);
+        assert!(set(&[start..=end]).is_empty());
+    }
+
+    #[test]
+    fn test_insert_at_the_limits() {
+        let mut set: IntervalSet<u8> = IntervalSet::from(250..=u8::MAX);
+        set.insert(0..=0);
+        set.insert(1..=249);
+
+        assert_eq!(set.intervals(), &[0..=u8::MAX]);
+        assert_eq!(set.len(), 256);
+    }
+
+    #[test]
+    fn test_len_of_whole_types() {
+        assert_eq!(IntervalSet::from(0..=u64::MAX).len(), 1 << 64);
+        assert_eq!(IntervalSet::from(i64::MIN..=i64::MAX).len(), 1 << 64);
+        assert_eq!(IntervalSet::from(0..=usize::MAX).len(), usize::MAX as u128 + 1);
+
+        // two ranges that each hold most of a u64, more than a u64 between them
+        let halves: IntervalSet<i64> = [i64::MIN..=-2, 0..=i64::MAX].into_iter().collect();
+        assert_eq!(halves.len(), (1 << 64) - 1);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut ranges = set(&[1..=10, 20..=30]);
+
+        // split one in two
+        ranges.remove(4..=6);
+        assert_eq!(ranges.intervals(), &[1..=3, 7..=10, 20..=30]);
+
+        // trim the ends off two
+        ranges.remove(9..=21);
+        assert_eq!(ranges.intervals(), &[1..=3, 7..=8, 22..=30]);
+
+        // take out whole ones, and nothing at all
+        ranges.remove(0..=8);
+        ranges.remove(40..=50);
+        assert_eq!(ranges.intervals(), &[22..=30]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let ranges = set(&[-1..=5, 7..=8]);
+
+        assert_eq!(ranges.len(), 9);
+        assert!(ranges.contains(-1));
+        assert!(ranges.contains(8));
+        assert!(!ranges.contains(6));
+        assert!(!ranges.contains(9));
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_combining() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 20..=21]);
+
+        assert_eq!(a.union(&b).intervals(), &[1..=15, 20..=21]);
+        assert_eq!(a.intersection(&b).intervals(), &[4..=5, 10..=11]);
+        assert_eq!(a.difference(&b).intervals(), &[1..=3, 12..=15]);
+        assert_eq!(b.difference(&a).intervals(), &[6..=9, 20..=21]);
+        assert!(a.intersection(&set(&[6..=9])).is_empty());
+    }
+
+    #[test]
+    fn test_complement() {
+
+        // the set is empty
+        assert_eq!(set(&[]).complement(1..=10).intervals(), &[1..=10]);
+
+        // the bounds are within the set's total range
+        assert_eq!(set(&[3..=5, 7..=8, 11..=13]).complement(3..=13).intervals(), &[6..=6, 9..=10]);
+
+        // the bounds start before and end after the set
+        assert_eq!(set(&[3..=5, 7..=8, 11..=13]).complement(1..=15).intervals(),
+                   &[1..=2, 6..=6, 9..=10, 14..=15]);
+
+        // the bounds start and end within the set's total range
+        assert_eq!(set(&[3..=5, 7..=8, 11..=13, 15..=17]).complement(8..=13).intervals(), &[9..=10]);
+
+        // the bounds start and end in the midst of a gap
+        assert_eq!(set(&[3..=5, 10..=14, 19..=20]).complement(8..=16).intervals(), &[8..=9, 15..=16]);
+
+        // the bounds fully take in a single range
+        assert_eq!(set(&[3..=5]).complement(1..=7).intervals(), &[1..=2, 6..=7]);
+
+        // the bounds are inside a single range
+        assert!(set(&[1..=5]).complement(2..=4).is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+/*  Splitting independent pieces of work across threads, with the results kept in order  */
+
+use std::cell::Cell;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// 0 until it's set, to fall back on AOC_THREADS or the number of cores
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // set on the threads indexed() starts, so work split up inside work that's already
+    // been split runs on the thread it's given instead of starting threads of its own
+    static WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+// how many threads to split work across: whatever set_threads() was given, otherwise
+// the AOC_THREADS environment variable, otherwise one per core. always 1 on a thread
+// that's already doing part of a split
+pub fn threads() -> usize {
+    if WORKER.with(Cell::get) { return 1 }
+
+    match THREADS.load(Ordering::Relaxed) {
+        0 => std::env::var("AOC_THREADS").ok()
+                                         .and_then(|threads| threads.parse().ok())
+                                         .filter(|&threads| threads > 0)
+                                         .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())),
+        n => n
+    }
+}
+
+// 1 runs everything on the calling thread, like a plain loop
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+// f applied to every item, the same as items.iter().map(f).collect() but spread across
+// threads. the results come back in the same order as the items, however the work was split
+pub fn map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send
+{
+    indexed(items.len(), threads(), |i| f(&items[i]))
+}
+
+// f applied to every number in the range, in order
+pub fn map_range<R: Send>(range: Range<usize>, f: impl Fn(usize) -> R + Sync) -> Vec<R> {
+    indexed(range.len(), threads(), |i| f(range.start + i))
+}
+
+// the range cut into runs of consecutive numbers, a few for each thread, and f applied to
+// each run. for when there are too many numbers to hand out one at a time, or when
+// neighbouring numbers can share some work. the results are in the order of the runs
+pub fn chunks<R: Send>(range: Range<usize>, f: impl Fn(Range<usize>) -> R + Sync) -> Vec<R> {
+    let pieces = split(range, threads() * 4);
+    indexed(pieces.len(), threads(), |i| f(pieces[i].clone()))
+}
+
+// cut a range into at most this many runs, as even in length as they can be
+fn split(range: Range<usize>, pieces: usize) -> Vec<Range<usize>> {
+    let pieces = pieces.clamp(1, range.len().max(1));
+    let (size, extra) = (range.len() / pieces, range.len() % pieces);
+
+    let mut start = range.start;
+
+    (0..pieces).map(|piece| {
+                   let end = start + size + usize::from(piece < extra);
+                   let run = start..end;
+                   start = end;
+                   run
+               })
+               .collect()
+}
+
+// f(0) to f(count - 1) across the threads. each thread takes the next index nobody's
+// started on yet, so a slow one doesn't hold up the rest, then the results are put back
+// in order at the end
+fn indexed<R: Send>(count: usize, threads: usize, f: impl Fn(usize) -> R + Sync) -> Vec<R> {
+    let threads = threads.min(count);
+    if threads <= 1 { return (0..count).map(f).collect() }
+
+    let next = AtomicUsize::new(0);
+
+    let work = || {
+        WORKER.with(|worker| worker.set(true));
+        let mut done = vec![];
+
+        loop {
+            let i = next.fetch_add(1, Ordering::Relaxed);
+            if i >= count { return done }
+
+            done.push((i, f(i)));
+        }
+    };
+
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|_| scope.spawn(work)).collect();
+
+        // a panic in any of them carries on up from here, as if there were no threads
+        workers.into_iter()
+               .flat_map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+               .collect()
+    });
+
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexed() {
+        let squares: Vec<usize> = (0..1000).map(|n| n * n).collect();
+
+        for threads in [1, 2, 3, 8, 2000] {
+            assert_eq!(indexed(1000, threads, |n| n * n), squares);
+        }
+
+        assert!(indexed(0, 4, |n| n).is_empty());
+    }
+
+    #[test]
+    fn test_uneven_work() {
+        // the slow ones early on don't change the order the results come back in
+        let slow = |n: usize| {
+            if n.is_multiple_of(7) { thread::sleep(std::time::Duration::from_millis(2)) }
+            n
+        };
+
+        assert_eq!(indexed(50, 4, slow), (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(split(0..10, 3), [0..4, 4..7, 7..10]);
+        assert_eq!(split(5..7, 4), [5..6, 6..7]);
+
+        // nothing to split still makes one empty run
+        let empty = split(3..3, 4);
+        assert_eq!(empty.len(), 1);
+        assert!(empty[0].is_empty());
+    }
+
+    #[test]
+    fn test_map() {
+        let words = ["one", "three", "five"];
+
+        assert_eq!(map(&words, |word| word.len()), [3, 5, 4]);
+        assert_eq!(map_range(10..14, |n| n % 3), [1, 2, 0, 1]);
+
+        let sums = chunks(0..100, |run| run.sum::<usize>());
+        assert_eq!(sums.into_iter().sum::<usize>(), 4950);
+    }
+
+    #[test]
+    fn test_nested() {
+        // the outer split has the threads, so the inner ones each run as a plain loop
+        let inner = indexed(4, 4, |_| threads());
+        assert_eq!(inner, [1, 1, 1, 1]);
+
+        let sums = indexed(4, 4, |n| map_range(0..n + 1, |m| m).into_iter().sum::<usize>());
+        assert_eq!(sums, [0, 1, 3, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "seven")]
+    fn test_panic() {
+        indexed(10, 3, |n| if n == 7 { panic!("seven") } else { n });
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{Image, Palette};
+use std::collections::HashMap;
+use std::time::Duration;
+
+// pictures as the frames of a GIF that loops forever, showing each for the delay (in
+// hundredths of a second, the finest a GIF can do). they all have to be the same size
+pub fn gif(frames: &[Image], palette: &Palette, delay: Duration) -> Vec<u8> {
+    let (width, height) = frames.first().map_or((0, 0), |frame| (frame.width(), frame.height()));
+    assert!(frames.iter().all(|frame| (frame.width(), frame.height()) == (width, height)),
+            "every frame of a GIF has to be the same size");
+
+    // the colour table's size is a power of two, at least 2
+    let bits = (usize::BITS - (palette.colours().len() - 1).leading_zeros()).max(1) as u8;
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(dimension(width));
+    gif.extend(dimension(height));
+    gif.extend([0x80 | 0x70 | (bits - 1), 0, 0]);
+
+    let mut table = palette.colours().concat();
+    table.resize(3 << bits, 0);
+    gif.extend(table);
+
+    // loop forever
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\0\0\0");
+
+    let delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+
+    for frame in frames {
+        // how long to show the frame, then leave it in place for the next one to go over
+        gif.extend([0x21, 0xf9, 4, 0x04]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0, 0]);
+
+        // the frame covers the whole picture, using the global colour table
+        gif.push(0x2c);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend(dimension(width));
+        gif.extend(dimension(height));
+        gif.push(0);
+
+        // the smallest code size LZW allows is 2, even for two colours
+        let code_size = bits.max(2);
+        gif.push(code_size);
+
+        for block in lzw(&frame.rows().take(height).flatten().copied().collect::<Vec<u8>>(), code_size).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0);
+    }
+
+    gif.push(0x3b);
+    gif
+}
+
+fn dimension(n: usize) -> [u8; 2] {
+    u16::try_from(n).expect("a GIF can't be more than 65535 pixels across").to_le_bytes()
+}
+
+
+/* LZW */
+
+// codes can grow to 12 bits, then the table has to be cleared and started again
+const MAX_CODES: u16 = 1 << 12;
+
+// compress the pixels with the variable-width LZW that GIF uses. codes 0 up to the clear
+// code stand for single pixels, and every code after the end code stands for a run of
+// pixels seen before
+fn lzw(pixels: &[u8], code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << code_size;
+    let end   = clear + 1;
+
+    let mut out   = Bits::default();
+    let mut table = HashMap::new();
+    let mut width = code_size + 1;
+    let mut next  = end + 1;
+
+    out.write(clear, width);
+
+    let Some((&first, rest)) = pixels.split_first() else {
+        out.write(end, width);
+        return out.finish()
+    };
+
+    // the code for the longest run seen before that the pixels so far match
+    let mut run = first as u16;
+
+    for &pixel in rest {
+        if let Some(&code) = table.get(&(run, pixel)) {
+            run = code;
+            continue
+        }
+
+        out.write(run, width);
+
+        if next == MAX_CODES {
+            out.write(clear, width);
+            table.clear();
+            width = code_size + 1;
+            next  = end + 1;
+        }
+        else {
+            table.insert((run, pixel), next);
+            next += 1;
+
+            // the decoder adds each code one step behind, so it only needs a wider code
+            // once the code after this one doesn't fit
+            if next > 1 << width && width < 12 { width += 1 }
+        }
+
+        run = pixel as u16;
+    }
+
+    out.write(run, width);
+
+    // the decoder adds one last code on reading the run, which can make the end code wider
+    if next < MAX_CODES && next + 1 > 1 << width && width < 12 { width += 1 }
+    out.write(end, width);
+
+    out.finish()
+}
+
+// codes of any width packed together, least significant bit first
+#[derive(Default)]
+struct Bits {
+    bytes  : Vec<u8>,
+    pending: u32,
+    count  : u8
+}
+
+impl Bits {
+    fn write(&mut self, code: u16, width: u8) {
+        self.pending |= (code as u32) << self.count;
+        self.count += width;
+
+        while self.count >= 8 {
+            self.bytes.push(self.pending as u8);
+            self.pending >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 { self.bytes.push(self.pending as u8) }
+        self.bytes
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the LZW decoder from the GIF spec, to check the encoder against
+    fn unlzw(bytes: &[u8], code_size: u8) -> Vec<u8> {
+        let clear = 1u16 << code_size;
+        let end   = clear + 1;
+
+        let mut pixels = vec![];
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut width = code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+
+        let (mut bit, total) = (0, bytes.len() * 8);
+
+        while bit + width as usize <= total {
+            let mut code = 0u16;
+            for i in 0..width as usize {
+                let at = bit + i;
+                code |= (((bytes[at / 8] >> (at % 8)) & 1) as u16) << i;
+            }
+            bit += width as usize;
+
+            if code == clear {
+                table = (0..clear).map(|n| vec![n as u8]).chain([vec![], vec![]]).collect();
+                width = code_size + 1;
+                previous = None;
+                continue
+            }
+            if code == end { break }
+
+            let entry = match (table.get(code as usize), &previous) {
+                (Some(entry), _)     => entry.clone(),
+                (None, Some(run))    => [run.clone(), vec![run[0]]].concat(),
+                (None, None)         => panic!("a code that isn't in the table yet")
+            };
+
+            if let Some(run) = previous {
+                if table.len() < MAX_CODES as usize {
+                    table.push([run, vec![entry[0]]].concat());
+                    if table.len() == 1 << width && width < 12 { width += 1 }
+                }
+            }
+
+            pixels.extend(&entry);
+            previous = Some(entry);
+        }
+
+        pixels
+    }
+
+    #[test]
+    fn test_lzw() {
+        assert_eq!(lzw(&[], 2), [0b0010_1100]);
+        assert_eq!(unlzw(&lzw(&[1, 1, 1, 1, 2], 2), 2), [1, 1, 1, 1, 2]);
+
+        // enough that the codes widen all the way to 12 bits and the table gets cleared
+        let pixels: Vec<u8> = (0..200_000u32).map(|n| (n.wrapping_mul(2654435761) >> 13) as u8 % 5).collect();
+        assert_eq!(unlzw(&lzw(&pixels, 3), 3), pixels);
+
+        // and on every length around where a code widens
+        for length in 0..600 {
+            let pixels: Vec<u8> = (0..length).map(|n| (n * n % 7) as u8 % 4).collect();
+            assert_eq!(unlzw(&lzw(&pixels, 2), 2), pixels);
+        }
+    }
+
+    #[test]
+    fn test_gif() {
+        let palette = Palette::new([0, 0, 0], &[('#', [255, 255, 255]), ('o', [255, 0, 0])]);
+        let frames  = [Image::from_text("#o\n.#", &palette, 1), Image::from_text("o#\n#.", &palette, 1)];
+
+        let gif = gif(&frames, &palette, Duration::from_millis(100));
+
+        // 2x2, a 4 colour table, then black, white, red and an unused black
+        assert!(gif.starts_with(b"GIF89a\x02\0\x02\0\xf1\0\0\0\0\0\xff\xff\xff\xff\0\0\0\0\0"));
+        assert_eq!(gif.iter().filter(|&&byte| byte == 0x2c).count(), 2);
+        assert!(gif.ends_with(&[0, 0x3b]));
+
+        // the second frame's delay is a tenth of a second
+        let control = gif.windows(4).rposition(|window| window == [0x21, 0xf9, 4, 0x04]).unwrap();
+        assert_eq!(&gif[control + 4..control + 6], &[10, 0]);
+    }
+}
@@ -0,0 +1,168 @@
+/*  Indexed-colour pictures of text grids, written out as PNG stills and GIF animations  */
+
+mod gif;
+mod png;
+
+pub use gif::gif;
+pub use png::png;
+
+// red, green and blue
+pub type Rgb = [u8; 3];
+
+// the colours a picture can use, and which character of a text frame is drawn in which.
+// the background is always the first colour
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Palette {
+    colours: Vec<Rgb>,
+    cells  : Vec<(char, u8)>,
+
+    // the colour of any character that isn't listed
+    unlisted: u8
+}
+
+impl Palette {
+    // characters that aren't listed are drawn as background
+    pub fn new(background: Rgb, cells: &[(char, Rgb)]) -> Self {
+        let mut palette = Palette { colours: vec![background], cells: vec![], unlisted: 0 };
+
+        for &(c, colour) in cells {
+            // characters drawn in the same colour share an entry
+            let index = match palette.colours.iter().position(|&known| known == colour) {
+                Some(index) => index,
+                None => {
+                    palette.colours.push(colour);
+                    palette.colours.len() - 1
+                }
+            };
+
+            assert!(index < 256, "a palette can only have 256 colours");
+            palette.cells.push((c, index as u8));
+        }
+
+        palette
+    }
+
+    pub fn colours(&self) -> &[Rgb] {
+        &self.colours
+    }
+
+    // where this character's colour is in the palette
+    pub fn index(&self, c: char) -> u8 {
+        self.cells.iter()
+                  .find(|&&(cell, _)| cell == c)
+                  .map_or(self.unlisted, |&(_, index)| index)
+    }
+}
+
+// light grey on black, for simulations that don't choose their own colours. '.' and ' '
+// are empty space and everything else is drawn
+impl Default for Palette {
+    fn default() -> Self {
+        Palette { colours: vec![[0, 0, 0], [192, 192, 192]], cells: vec![('.', 0), (' ', 0)], unlisted: 1 }
+    }
+}
+
+// a picture where every pixel is an index into a palette
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width : usize,
+    height: usize,
+    pixels: Vec<u8>
+}
+
+impl Image {
+    // all background
+    pub fn new(width: usize, height: usize) -> Self {
+        Image { width, height, pixels: vec![0; width * height] }
+    }
+
+    // each character of the text becomes a square of scale by scale pixels, in the colour
+    // the palette gives it. short lines are filled out with background
+    pub fn from_text(text: &str, palette: &Palette, scale: usize) -> Self {
+        let rows: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        let mut image = Image::new(columns * scale, rows.len() * scale);
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                image.fill(x * scale, y * scale, scale, palette.index(c));
+            }
+        }
+
+        image
+    }
+
+    pub fn width(&self)  -> usize { self.width  }
+    pub fn height(&self) -> usize { self.height }
+
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    // a bigger picture with this one in its top left corner, and background around it
+    pub fn padded(&self, width: usize, height: usize) -> Self {
+        assert!(width >= self.width && height >= self.height, "padding can't shrink a picture");
+
+        let mut padded = Image::new(width, height);
+
+        for (y, row) in self.rows().enumerate() {
+            padded.pixels[y * width..y * width + self.width].copy_from_slice(row);
+        }
+
+        padded
+    }
+
+    fn fill(&mut self, left: usize, top: usize, size: usize, index: u8) {
+        for y in top..top + size {
+            self.pixels[y * self.width + left..y * self.width + left + size].fill(index);
+        }
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED : Rgb = [255, 0, 0];
+    const BLUE: Rgb = [0, 0, 255];
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::new([0, 0, 0], &[('#', RED), ('o', BLUE), ('@', RED)]);
+
+        assert_eq!(palette.colours(), &[[0, 0, 0], RED, BLUE]);
+        assert_eq!(palette.index('#'), 1);
+        assert_eq!(palette.index('@'), 1);
+        assert_eq!(palette.index('o'), 2);
+        assert_eq!(palette.index('.'), 0);
+    }
+
+    #[test]
+    fn test_from_text() {
+        let palette = Palette::new([0, 0, 0], &[('#', RED), ('o', BLUE)]);
+        let image = Image::from_text("#.\no\n", &palette, 2);
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.rows().collect::<Vec<_>>(), [[1, 1, 0, 0], [1, 1, 0, 0], [2, 2, 0, 0], [2, 2, 0, 0]]);
+
+        // without a palette of its own, anything that isn't empty space shows up
+        let image = Image::from_text("#. o", &Palette::default(), 1);
+        assert_eq!(image.rows().next().unwrap(), [1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_padded() {
+        let image = Image::from_text("##", &Palette::default(), 1).padded(3, 2);
+
+        assert_eq!(image.rows().collect::<Vec<_>>(), [[1, 1, 0], [0, 0, 0]]);
+        assert_eq!(image.get(1, 0), 1);
+    }
+}
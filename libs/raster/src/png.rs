@@ -0,0 +1,117 @@
+use crate::{Image, Palette};
+
+// a picture as a PNG file. the pixel data is stored without compressing it, which keeps
+// this short and the output the same every time, at the cost of bigger files
+pub fn png(image: &Image, palette: &Palette) -> Vec<u8> {
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    // 8 bits per pixel, indexed colour, no interlacing
+    let mut header = vec![];
+    header.extend((image.width()  as u32).to_be_bytes());
+    header.extend((image.height() as u32).to_be_bytes());
+    header.extend([8, 3, 0, 0, 0]);
+    chunk(&mut png, b"IHDR", &header);
+
+    chunk(&mut png, b"PLTE", &palette.colours().concat());
+
+    // every row starts with the filter it was run through, which is none
+    let mut scanlines = vec![];
+    for row in image.rows().take(image.height()) {
+        scanlines.push(0);
+        scanlines.extend(row);
+    }
+    chunk(&mut png, b"IDAT", &zlib(&scanlines));
+
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+// length, type, data, then a checksum of the type and the data
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// a zlib stream made of deflate's stored blocks, each holding up to 65535 bytes as is
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = if data.is_empty() { vec![&[]] } else { data.chunks(0xffff).collect() };
+
+    for (i, block) in blocks.iter().enumerate() {
+        let last = i == blocks.len() - 1;
+        let len  = block.len() as u16;
+
+        stream.push(last as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(*block);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_zlib() {
+        assert_eq!(zlib(b"abc"), [0x78, 0x01, 1, 3, 0, 0xfc, 0xff, b'a', b'b', b'c', 0x02, 0x4d, 0x01, 0x27]);
+
+        // too much for one block
+        let stream = zlib(&[7; 70000]);
+        assert_eq!(stream[2], 0);
+        assert_eq!(stream[2 + 5 + 0xffff], 1);
+    }
+
+    #[test]
+    fn test_png() {
+        let palette = Palette::new([0, 0, 0], &[('#', [255, 255, 255])]);
+        let png = png(&Image::from_text("#.\n.#", &palette, 1), &palette);
+
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x02\x08\x03"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    }
+}
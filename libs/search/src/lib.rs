@@ -0,0 +1,256 @@
+/*  Shortest paths over any graph, given a closure that says where you can go from a node  */
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// what a search found: how far every reached node is from the start, and the node each
+// one was reached from, for following a path back
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    previous : HashMap<N, N>
+}
+
+impl<N, C> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy
+{
+    // None if the node couldn't be reached
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    // every node the search reached, the start included, in no particular order
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> {
+        self.distances.iter().map(|(node, &distance)| (node, distance))
+    }
+
+    // the nodes along a shortest path from the start to this one, both ends included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) { return None }
+
+        let mut path = vec![node.clone()];
+
+        while let Some(previous) = self.previous.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+
+/* Breadth-first */
+
+// every node reachable from the start, when every step costs 1
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>
+{
+    let mut paths = Paths { distances: HashMap::new(), previous: HashMap::new() };
+    paths.distances.insert(start.clone(), 0);
+
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(u) = queue.pop_front() {
+        let distance = paths.distances[&u] + 1;
+
+        for v in neighbours(&u) {
+            if paths.distances.contains_key(&v) { continue }
+
+            paths.distances.insert(v.clone(), distance);
+            paths.previous.insert(v.clone(), u.clone());
+            queue.push_back(v);
+        }
+    }
+
+    paths
+}
+
+
+/* Dijkstra */
+
+// every node reachable from the start, where each neighbour comes with the cost of
+// stepping to it. costs can't be negative, and C::default() is a cost of nothing
+pub fn dijkstra<N, C, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>
+{
+    let mut paths = Paths { distances: HashMap::new(), previous: HashMap::new() };
+    paths.distances.insert(start.clone(), C::default());
+
+    let mut queue = BinaryHeap::from([Queued { cost: C::default(), priority: C::default(), node: start }]);
+
+    while let Some(Queued { cost, node: u, .. }) = queue.pop() {
+        // a stale entry, the node was reached more cheaply since this was queued
+        if cost > paths.distances[&u] { continue }
+
+        for (v, step) in neighbours(&u) {
+            let alt = cost + step;
+
+            if paths.distances.get(&v).is_some_and(|&known| known <= alt) { continue }
+
+            paths.distances.insert(v.clone(), alt);
+            paths.previous.insert(v.clone(), u.clone());
+            queue.push(Queued { cost: alt, priority: alt, node: v });
+        }
+    }
+
+    paths
+}
+
+
+/* A* */
+
+// the cheapest path from the start to the nearest node that passes the goal test, and its
+// cost. the heuristic guesses the cost from a node to a goal, and has to never guess high
+// or the path found might not be the cheapest
+pub fn astar<N, C, I>(start        : N,
+                      mut neighbours: impl FnMut(&N) -> I,
+                      mut heuristic : impl FnMut(&N) -> C,
+                      mut is_goal   : impl FnMut(&N) -> bool) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>
+{
+    let mut paths = Paths { distances: HashMap::new(), previous: HashMap::new() };
+    paths.distances.insert(start.clone(), C::default());
+
+    let priority = heuristic(&start);
+    let mut queue = BinaryHeap::from([Queued { cost: C::default(), priority, node: start }]);
+
+    while let Some(Queued { cost, node: u, .. }) = queue.pop() {
+        if cost > paths.distances[&u] { continue }
+
+        if is_goal(&u) {
+            return Some((cost, paths.path_to(&u).unwrap()))
+        }
+
+        for (v, step) in neighbours(&u) {
+            let alt = cost + step;
+
+            if paths.distances.get(&v).is_some_and(|&known| known <= alt) { continue }
+
+            let priority = alt + heuristic(&v);
+
+            paths.distances.insert(v.clone(), alt);
+            paths.previous.insert(v.clone(), u.clone());
+            queue.push(Queued { cost: alt, priority, node: v });
+        }
+    }
+
+    None
+}
+
+
+/* Priority queue entries */
+
+// BinaryHeap pops the largest first, so these order backwards by priority to pop the
+// smallest. the node itself doesn't need to be comparable
+struct Queued<N, C> {
+    cost    : C,
+    priority: C,
+    node    : N
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //   a --1-- b --1-- c
+    //    \             /
+    //     ------5------
+    //            \
+    //             d (nothing leads back out of d)
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('a', 5), ('d', 2)],
+            _   => vec![]
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        weighted(node).into_iter().map(|(node, _)| node).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let paths = bfs('a', unweighted);
+
+        assert_eq!(paths.distance(&'a'), Some(0));
+        assert_eq!(paths.distance(&'c'), Some(1));
+        assert_eq!(paths.distance(&'d'), Some(2));
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'c', 'd']));
+        assert_eq!(paths.reached().count(), 4);
+
+        // only the way in
+        let paths = bfs('d', unweighted);
+        assert_eq!(paths.distance(&'a'), None);
+        assert_eq!(paths.path_to(&'a'), None);
+        assert_eq!(paths.path_to(&'d'), Some(vec!['d']));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra('a', weighted);
+
+        assert_eq!(paths.distance(&'c'), Some(2));
+        assert_eq!(paths.distance(&'d'), Some(4));
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn test_astar() {
+        // with no heuristic at all it's dijkstra that stops at the goal
+        assert_eq!(astar('a', weighted, |_| 0, |&node| node == 'd'), Some((4, vec!['a', 'b', 'c', 'd'])));
+        assert_eq!(astar('d', weighted, |_| 0, |&node| node == 'a'), None);
+
+        // a grid, heading for the far corner with manhattan distance as the guess
+        let size = 20;
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].into_iter()
+                .filter(|&(x, y)| (0..size).contains(&x) && (0..size).contains(&y))
+                .filter(|&(x, y)| !(x == 10 && y < 15))  // a wall to get around
+                .map(|node| (node, 1))
+        };
+
+        let (cost, path) = astar((0, 0), neighbours, |&(x, y)| (size - 1 - x) + (size - 1 - y),
+                                 |&node| node == (size - 1, size - 1)).unwrap();
+
+        assert_eq!(cost, 38);
+        assert_eq!(path.len(), 39);
+        assert_eq!(path.first(), Some(&(0, 0)));
+    }
+}
@@ -1,16 +1,26 @@
 /*  https://adventofcode.com/2022/day/i  */
 
-fn main() {
-    let input = Input::from("input.txt");
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+use common::{Answer, ParseError, Solution};
+use common::parse::lines;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u32 = i;
+
+    type Input = Input;
+
+    fn parse(s: &str) -> Result<Input, ParseError> { Input::from_string(s.trim_end()) }
+
+    fn part1(input: &Input) -> Answer { part1(input).into() }
+    fn part2(input: &Input) -> Answer { part2(input).into() }
 }
 
-struct Input { field : Vec<u32> }
+pub struct Input { field : Vec<u32> }
 
 //
-fn part1(_input: &Input) -> u32 {
-    0
+fn part1(input: &Input) -> u32 {
+    input.field.iter().sum()
 }
 
 //
@@ -22,15 +32,11 @@ fn part2(_input: &Input) -> u32 {
 /* Parsing */
 
 impl Input {
-    fn from(file: &str) -> Self {
-        let contents = std::fs::read_to_string(file).expect("Couldn't read input");
-        Input::from_string(contents.trim())
-    }
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let field = lines(s).map(|line| line.number())
+                            .collect::<Result<_, _>>()?;
 
-    fn from_string(s: &str) -> Self {
-        Input {
-            field: vec![]
-        }
+        Ok(Input { field })
     }
 }
 
@@ -41,24 +47,20 @@ impl Input {
 mod tests {
     use super::*;
 
+    // the answers to example.txt from the puzzle text, ignored until they're filled in
     #[test]
-    fn test_() {
-        assert_eq!(1, 1);
-    }
-
-    #[test]
+    #[ignore = "needs the example's answer"]
     fn test_part1() {
         assert_eq!(part1(&get_example()), 0);
     }
 
     #[test]
+    #[ignore = "needs the example's answer"]
     fn test_part2() {
         assert_eq!(part2(&get_example()), 0);
     }
 
     fn get_example() -> Input {
-        Input::from_string(
-            ""
-        )
+        DayNN::parse(common::example!()).unwrap()
     }
 }